@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::Errors, state::ProgramConfig};
+
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let new_admin = ctx.accounts.new_admin.key();
+
+    require!(config.pending_admin.is_some(), Errors::NoPendingAdmin);
+    require!(config.pending_admin == Some(new_admin), Errors::Unauthorized);
+
+    config.admin = new_admin;
+    config.pending_admin = None;
+
+    msg!("Admin handover accepted by: {}", new_admin);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub new_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"program_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::Errors, state::{OperatorRole, ProgramConfig, Role}};
+
+pub fn grant_role(ctx: Context<GrantRole>, operator: Pubkey, role: Role) -> Result<()> {
+    require!(ctx.accounts.admin.key() == ctx.accounts.config.admin, Errors::Unauthorized);
+
+    let operator_role = &mut ctx.accounts.operator_role;
+    operator_role.operator = operator;
+    operator_role.role = role;
+    operator_role.bump = ctx.bumps.operator_role;
+
+    msg!("Granted {:?} role to: {}", role, operator);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(operator: Pubkey, role: Role)]
+pub struct GrantRole<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + OperatorRole::SIZE,
+        seeds = [b"operator_role", operator.as_ref(), &[role as u8]],
+        bump
+    )]
+    pub operator_role: Account<'info, OperatorRole>,
+
+    pub system_program: Program<'info, System>,
+}
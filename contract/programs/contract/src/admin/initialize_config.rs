@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::{DEFAULT_APPEAL_WINDOW, DEFAULT_MAX_HEARTBEAT_GAP}, errors::Errors, program::Contract, state::ProgramConfig};
+
+pub fn initialize_config(ctx: Context<InitializeConfig>, admin: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.admin = admin;
+    config.pending_admin = None;
+    config.paused = 0;
+    config.vaults = [Pubkey::default(); 4];
+    config.protocol_fee_bps = 0;
+    config.referrer_fee_bps = 0;
+    config.cancellation_fee_bps = 0;
+    config.expiry_bounty = 0;
+    config.allowed_mints = Vec::new();
+    config.unbonding_period = 0;
+    config.appeal_window = DEFAULT_APPEAL_WINDOW;
+    config.max_heartbeat_gap = DEFAULT_MAX_HEARTBEAT_GAP;
+    config.bump = ctx.bumps.config;
+
+    msg!("Program config initialized with admin: {}", admin);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ProgramConfig::SIZE,
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ Errors::Unauthorized)]
+    pub program: Program<'info, Contract>,
+
+    #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()) @ Errors::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,29 @@
+pub mod initialize_config;
+pub mod propose_admin;
+pub mod accept_admin;
+pub mod grant_role;
+pub mod revoke_role;
+pub mod set_pause;
+pub mod set_treasury_policy;
+pub mod set_fee_config;
+pub mod set_machine_tier;
+pub mod set_expiry_bounty;
+pub mod set_allowed_mints;
+pub mod set_unbonding_period;
+pub mod set_appeal_window;
+pub mod set_max_heartbeat_gap;
+
+pub use initialize_config::*;
+pub use propose_admin::*;
+pub use accept_admin::*;
+pub use grant_role::*;
+pub use revoke_role::*;
+pub use set_pause::*;
+pub use set_treasury_policy::*;
+pub use set_fee_config::*;
+pub use set_machine_tier::*;
+pub use set_expiry_bounty::*;
+pub use set_allowed_mints::*;
+pub use set_unbonding_period::*;
+pub use set_appeal_window::*;
+pub use set_max_heartbeat_gap::*;
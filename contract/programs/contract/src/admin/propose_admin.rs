@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::Errors, state::ProgramConfig};
+
+pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require!(ctx.accounts.admin.key() == config.admin, Errors::Unauthorized);
+
+    config.pending_admin = Some(new_admin);
+
+    msg!("Admin handover proposed to: {}", new_admin);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"program_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::Errors, state::{OperatorRole, ProgramConfig, Role}};
+
+pub fn revoke_role(ctx: Context<RevokeRole>, operator: Pubkey, role: Role) -> Result<()> {
+    require!(ctx.accounts.admin.key() == ctx.accounts.config.admin, Errors::Unauthorized);
+
+    msg!("Revoked {:?} role from: {}", role, operator);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(operator: Pubkey, role: Role)]
+pub struct RevokeRole<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"operator_role", operator.as_ref(), &[role as u8]],
+        bump = operator_role.bump,
+    )]
+    pub operator_role: Account<'info, OperatorRole>,
+}
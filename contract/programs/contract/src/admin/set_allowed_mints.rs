@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::Errors, state::ProgramConfig};
+
+pub fn set_allowed_mints(ctx: Context<SetAllowedMints>, mints: Vec<Pubkey>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require!(ctx.accounts.admin.key() == config.admin, Errors::Unauthorized);
+    require!(mints.len() <= ProgramConfig::MAX_ALLOWED_MINTS, Errors::InvalidMintList);
+
+    config.allowed_mints = mints;
+
+    msg!("Allowed payment mints set: {}", config.allowed_mints.len());
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetAllowedMints<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"program_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
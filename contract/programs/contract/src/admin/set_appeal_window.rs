@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::Errors, state::ProgramConfig};
+
+pub fn set_appeal_window(ctx: Context<SetAppealWindow>, appeal_window: i64) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require!(ctx.accounts.admin.key() == config.admin, Errors::Unauthorized);
+    require!(appeal_window >= 0, Errors::InvalidDuration);
+
+    config.appeal_window = appeal_window;
+
+    msg!("Slash appeal window set to {}s", appeal_window);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetAppealWindow<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"program_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::Errors, state::ProgramConfig};
+
+pub fn set_expiry_bounty(ctx: Context<SetExpiryBounty>, expiry_bounty: u64) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require!(ctx.accounts.admin.key() == config.admin, Errors::Unauthorized);
+
+    config.expiry_bounty = expiry_bounty;
+
+    msg!("Expiry bounty set to {} lamports", expiry_bounty);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetExpiryBounty<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"program_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
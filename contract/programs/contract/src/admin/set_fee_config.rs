@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::BPS_DENOMINATOR, errors::Errors, state::ProgramConfig};
+
+pub fn set_fee_config(
+    ctx: Context<SetFeeConfig>,
+    protocol_fee_bps: u16,
+    referrer_fee_bps: u16,
+    cancellation_fee_bps: u16,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require!(ctx.accounts.admin.key() == config.admin, Errors::Unauthorized);
+    require!(
+        protocol_fee_bps as u64 + referrer_fee_bps as u64 <= BPS_DENOMINATOR,
+        Errors::InvalidFeeConfig
+    );
+    require!(cancellation_fee_bps as u64 <= BPS_DENOMINATOR, Errors::InvalidFeeConfig);
+
+    config.protocol_fee_bps = protocol_fee_bps;
+    config.referrer_fee_bps = referrer_fee_bps;
+    config.cancellation_fee_bps = cancellation_fee_bps;
+
+    msg!(
+        "Fees set: protocol {} bps, referrer {} bps, cancellation {} bps",
+        protocol_fee_bps,
+        referrer_fee_bps,
+        cancellation_fee_bps
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetFeeConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"program_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
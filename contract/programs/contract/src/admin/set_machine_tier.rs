@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::Errors, state::{MachineTier, ProgramConfig}};
+
+pub fn set_machine_tier(
+    ctx: Context<SetMachineTier>,
+    machine_type: String,
+    price_mint: Pubkey,
+    rate_per_second: u64,
+    min_stake: u64,
+) -> Result<()> {
+    require!(ctx.accounts.admin.key() == ctx.accounts.config.admin, Errors::Unauthorized);
+    require!(
+        !machine_type.is_empty() && machine_type.len() <= MachineTier::MAX_MACHINE_TYPE_LEN,
+        Errors::InvalidMachineTier
+    );
+    require!(rate_per_second > 0, Errors::InvalidMachineTier);
+
+    let tier = &mut ctx.accounts.machine_tier;
+    tier.machine_type = machine_type;
+    tier.price_mint = price_mint;
+    tier.rate_per_second = rate_per_second;
+    tier.min_stake = min_stake;
+    tier.bump = ctx.bumps.machine_tier;
+
+    msg!("Machine tier {} priced at {}/s in mint {}", tier.machine_type, rate_per_second, price_mint);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(machine_type: String, price_mint: Pubkey)]
+pub struct SetMachineTier<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + MachineTier::SIZE,
+        seeds = [b"machine_tier", machine_type.as_bytes(), price_mint.as_ref()],
+        bump
+    )]
+    pub machine_tier: Account<'info, MachineTier>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::Errors, state::ProgramConfig};
+
+pub fn set_max_heartbeat_gap(ctx: Context<SetMaxHeartbeatGap>, max_heartbeat_gap: i64) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require!(ctx.accounts.admin.key() == config.admin, Errors::Unauthorized);
+    require!(max_heartbeat_gap > 0, Errors::InvalidDuration);
+
+    config.max_heartbeat_gap = max_heartbeat_gap;
+
+    msg!("Maximum heartbeat gap set to {}s", max_heartbeat_gap);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetMaxHeartbeatGap<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"program_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{OperatorRole, PauseCategory, ProgramConfig, Role};
+
+pub fn set_pause(ctx: Context<SetPause>, category: PauseCategory, paused: bool) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.set_paused(category, paused);
+
+    msg!("{:?} paused: {}", category, paused);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    pub guardian: Signer<'info>,
+
+    #[account(
+        seeds = [b"operator_role", guardian.key().as_ref(), &[Role::Guardian as u8]],
+        bump = operator_role.bump,
+    )]
+    pub operator_role: Account<'info, OperatorRole>,
+
+    #[account(
+        mut,
+        seeds = [b"program_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
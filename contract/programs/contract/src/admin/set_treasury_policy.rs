@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::Errors, state::{PolicyChange, ProgramConfig, TreasuryPolicy}};
+
+pub fn set_treasury_policy(
+    ctx: Context<SetTreasuryPolicy>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+    timelock_seconds: i64,
+    withdrawal_limit: u64,
+) -> Result<()> {
+    require!(ctx.accounts.admin.key() == ctx.accounts.config.admin, Errors::Unauthorized);
+    require!(
+        !signers.is_empty() && signers.len() <= TreasuryPolicy::MAX_SIGNERS,
+        Errors::InvalidTreasuryPolicy
+    );
+    require!(
+        threshold > 0 && threshold as usize <= signers.len(),
+        Errors::InvalidTreasuryPolicy
+    );
+    require!(timelock_seconds >= 0, Errors::InvalidTreasuryPolicy);
+
+    let policy = &mut ctx.accounts.treasury_policy;
+
+    // Once signers exist the admin can only stage a change; it needs their approval and the current timelock
+    if policy.is_initialized() {
+        let executable_at = Clock::get()?.unix_timestamp.checked_add(policy.timelock_seconds)
+            .ok_or(Errors::ArithmeticOverflow)?;
+        policy.pending_change = Some(PolicyChange {
+            signers,
+            threshold,
+            timelock_seconds,
+            withdrawal_limit,
+            approvals: Vec::new(),
+            executable_at,
+        });
+        msg!("Treasury policy change staged, executable at {}", executable_at);
+        return Ok(());
+    }
+
+    policy.signers = signers;
+    policy.threshold = threshold;
+    policy.timelock_seconds = timelock_seconds;
+    policy.withdrawal_limit = withdrawal_limit;
+    policy.pending_change = None;
+    policy.bump = ctx.bumps.treasury_policy;
+
+    msg!(
+        "Treasury policy set: {}-of-{}, timelock {}s, limit {} lamports",
+        threshold,
+        policy.signers.len(),
+        timelock_seconds,
+        withdrawal_limit
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetTreasuryPolicy<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + TreasuryPolicy::SIZE,
+        seeds = [b"treasury_policy"],
+        bump
+    )]
+    pub treasury_policy: Account<'info, TreasuryPolicy>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::Errors, state::ProgramConfig};
+
+pub fn set_unbonding_period(ctx: Context<SetUnbondingPeriod>, unbonding_period: i64) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require!(ctx.accounts.admin.key() == config.admin, Errors::Unauthorized);
+    require!(unbonding_period >= 0, Errors::InvalidDuration);
+
+    config.unbonding_period = unbonding_period;
+
+    msg!("Stake unbonding period set to {}s", unbonding_period);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetUnbondingPeriod<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"program_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
@@ -1,3 +1,5 @@
-use anchor_lang::prelude::*;
-
-pub const ADMIN_PUBKEY: Pubkey = pubkey!("D8kz4JbFHtVcyE8AAcZGLeA28TwNm4JjpDaLBeqDzTwn");
\ No newline at end of file
+pub const BPS_DENOMINATOR: u64 = 10_000;
+pub const DEFAULT_MAX_HEARTBEAT_GAP: i64 = 300;
+pub const DEFAULT_APPEAL_WINDOW: i64 = 3 * 24 * 3_600;
+pub const SECONDS_PER_HOUR: u64 = 3_600;
+pub const WITHDRAWAL_WINDOW: i64 = 24 * 3_600;
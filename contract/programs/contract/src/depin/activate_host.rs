@@ -1,6 +1,6 @@
 use::anchor_lang::prelude::*;
 
-use crate::{constants::ADMIN_PUBKEY, errors::DepinErrors, state::HostMachineRegistration};
+use crate::{errors::{DepinErrors, Errors}, state::{HostMachineRegistration, HostStake, HostStatus, MachineTier, PauseCategory, ProgramConfig}};
 
 pub fn activate_host(
     ctx: Context<ActivateHost>,
@@ -11,7 +11,7 @@ pub fn activate_host(
     let host_machine = &mut ctx.accounts.host_machine;
 
     require!(
-        user.key() == host.key() || user.key() == ADMIN_PUBKEY,
+        host_machine.is_operated_by(&user.key()) || user.key() == ctx.accounts.config.admin,
         DepinErrors::UnauthorizedAdmin
     );
 
@@ -19,10 +19,6 @@ pub fn activate_host(
         host.key() == host_machine.host_key,
         DepinErrors::HostKeyMismatch
     );
-    require!(
-        host_machine.is_active == false,
-        DepinErrors::HostMachineRegistrationNotActive
-    );
     require!(
         host_machine.id == id,
         DepinErrors::InvalidHostMachineRegistrationId
@@ -32,12 +28,25 @@ pub fn activate_host(
         DepinErrors::HostMachineAlreadyStarted
     );
     require!(
-        host_machine.penalized == false,
+        host_machine.status != HostStatus::Penalized,
         DepinErrors::HostMachinePenalized
     );
 
+    // Hosts must have the tier's minimum collateral locked before they can serve rentals
+    let machine_tier = &ctx.accounts.machine_tier;
+    require!(
+        machine_tier.machine_type == host_machine.machine_type && machine_tier.price_mint == Pubkey::default(),
+        Errors::MachineTierMismatch
+    );
+    require!(
+        ctx.accounts.host_stake.amount >= machine_tier.min_stake,
+        DepinErrors::InsufficientStake
+    );
+
+    host_machine.status.transition(HostStatus::Online)?;
     host_machine.started_at = Clock::get()?.unix_timestamp;
-    host_machine.is_active = true;
+    host_machine.last_heartbeat = host_machine.started_at;
+    host_machine.covered_seconds = 0;
     msg!("Host machine activated successfully: {}", host_machine.id);
     Ok(())
 }
@@ -57,5 +66,20 @@ pub struct ActivateHost<'info> {
         bump
     )]
     pub host_machine: Account<'info, HostMachineRegistration>,
+
+    #[account(
+        seeds = [b"host_stake", host_machine.key().as_ref()],
+        bump = host_stake.bump,
+    )]
+    pub host_stake: Account<'info, HostStake>,
+
+    pub machine_tier: Account<'info, MachineTier>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Depin) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
     pub system_program: Program<'info, System>
 }
\ No newline at end of file
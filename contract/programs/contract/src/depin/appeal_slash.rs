@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::{DepinErrors, Errors}, events::SlashAppealed, state::{HostMachineRegistration, HostStatus, PauseCategory, ProgramConfig, SlashRecord}};
+
+pub fn appeal_slash(
+    ctx: Context<AppealSlash>,
+    id: String,
+    index: u64,
+    counter_evidence_hash: [u8; 32],
+) -> Result<()> {
+    let host_machine = &ctx.accounts.host_machine;
+    require!(host_machine.id == id, DepinErrors::InvalidHostMachineRegistrationId);
+    require!(host_machine.status == HostStatus::Penalized, DepinErrors::HostMachineNotPenalized);
+
+    let record = &mut ctx.accounts.slash_record;
+    require!(record.appeal_hash.is_none(), DepinErrors::SlashAlreadyAppealed);
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now <= record.created_at.saturating_add(ctx.accounts.config.appeal_window),
+        DepinErrors::AppealWindowClosed
+    );
+
+    record.appeal_hash = Some(counter_evidence_hash);
+    record.appealed_at = now;
+
+    emit!(SlashAppealed {
+        host_machine: host_machine.key(),
+        index,
+        counter_evidence_hash,
+    });
+
+    msg!("Host {} appealed slash #{}", id, index);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(id: String, index: u64)]
+pub struct AppealSlash<'info> {
+    pub host: Signer<'info>,
+
+    #[account(
+        seeds = [b"host_machine", host.key().as_ref(), id.as_bytes()],
+        bump = host_machine.bump,
+        constraint = host_machine.host_key == host.key() @ DepinErrors::HostKeyMismatch,
+    )]
+    pub host_machine: Account<'info, HostMachineRegistration>,
+
+    #[account(
+        mut,
+        seeds = [b"slash_record".as_ref(), host_machine.key().as_ref(), &index.to_le_bytes()],
+        bump = slash_record.bump,
+    )]
+    pub slash_record: Account<'info, SlashRecord>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Depin) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
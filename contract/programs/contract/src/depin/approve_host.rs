@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::{DepinErrors, Errors}, state::{HostMachineRegistration, HostStatus, OperatorRole, PauseCategory, ProgramConfig, Role}};
+
+pub fn approve_host(ctx: Context<ApproveHost>, id: String) -> Result<()> {
+    let host_machine = &mut ctx.accounts.host_machine;
+    require!(host_machine.status == HostStatus::Pending, DepinErrors::HostMachineNotPending);
+    host_machine.status.transition(HostStatus::Registered)?;
+
+    msg!("Host registration {} approved", id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(id: String)]
+pub struct ApproveHost<'info> {
+    pub registrar: Signer<'info>,
+
+    #[account(
+        seeds = [b"operator_role", registrar.key().as_ref(), &[Role::HostRegistrar as u8]],
+        bump = operator_role.bump,
+    )]
+    pub operator_role: Account<'info, OperatorRole>,
+
+    /// CHECK: Host key the registration was submitted under
+    pub host: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"host_machine", host.key().as_ref(), id.as_bytes()],
+        bump = host_machine.bump,
+    )]
+    pub host_machine: Account<'info, HostMachineRegistration>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Depin) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
@@ -0,0 +1,60 @@
+use::anchor_lang::prelude::*;
+use crate::{errors::{DepinErrors, Errors}, state::{HostMachineRegistration, HostStatus, PauseCategory, ProgramConfig, VaultAccount, VaultKind}};
+
+pub fn checkpoint_rewards(
+    ctx: Context<CheckpointRewards>,
+    id: String,
+) -> Result<()> {
+    let host_machine = &mut ctx.accounts.host_machine;
+    let user = &ctx.accounts.user;
+
+    require!(
+        host_machine.is_operated_by(&user.key()) || user.key() == ctx.accounts.config.admin,
+        DepinErrors::UnauthorizedAdmin
+    );
+    require!(
+        host_machine.status == HostStatus::Online,
+        DepinErrors::HostMachineRegistrationNotActive
+    );
+    require!(
+        host_machine.id == id,
+        DepinErrors::InvalidHostMachineRegistrationId
+    );
+
+    // Accrues heartbeat-covered uptime without taking the host offline
+    let reward = host_machine.accrue_rewards()?;
+    ctx.accounts.vault_account.add_liability(reward)?;
+
+    msg!("Host machine {} checkpointed {} rewards. Earned: {}", id, reward, host_machine.earned);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(id: String)]
+pub struct CheckpointRewards<'info> {
+    pub user: Signer<'info>,
+
+    ///CHECK: This account must be the host's key
+    pub host: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"host_machine", host.key().as_ref(), id.as_bytes()],
+        bump
+    )]
+    pub host_machine: Account<'info, HostMachineRegistration>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", &[VaultKind::RewardsPool as u8]],
+        bump = vault_account.bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Depin) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
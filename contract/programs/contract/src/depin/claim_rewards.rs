@@ -1,23 +1,18 @@
 use::anchor_lang::prelude::*;
-use crate::{errors::{DepinErrors, Errors}, state::{HostMachineRegistration, VaultAccount}};
+use crate::{errors::{DepinErrors, Errors}, state::{HostMachineRegistration, HostStatus, PauseCategory, ProgramConfig, VaultAccount, VaultKind}};
 
 pub fn claim_rewards(
     ctx: Context<ClaimRewards>,
     id: String,
-    _secret_key: String,
 ) -> Result<()> {
     let host_machine = &mut ctx.accounts.host_machine;
     let vault_account = &mut ctx.accounts.vault_account;
     let host = &ctx.accounts.host;
 
     require!(
-        host_machine.penalized == false,
+        host_machine.status != HostStatus::Penalized,
         DepinErrors::HostMachinePenalized
     );
-    require!(
-        host_machine.is_active == false,
-        DepinErrors::HostMachineShouldNotBeActiveForClaiming
-    );
     require!(
         host_machine.id == id,
         DepinErrors::InvalidHostMachineRegistrationId
@@ -27,25 +22,29 @@ pub fn claim_rewards(
         DepinErrors::HostKeyMismatch
     );
 
-    require!(
-        vault_account.owner == ctx.accounts.admin.key(),
-        Errors::Unauthorized
-    );
+    // Online hosts are paid up to now and keep serving; the claim counts as a heartbeat
+    if host_machine.status == HostStatus::Online {
+        host_machine.record_heartbeat(Clock::get()?.unix_timestamp, ctx.accounts.config.max_heartbeat_gap)?;
+        let reward = host_machine.accrue_rewards()?;
+        vault_account.add_liability(reward)?;
+    }
 
+    let earned = host_machine.earned;
     require!(
-        **vault_account.to_account_info().lamports.borrow() >= host_machine.earned,
+        **vault_account.to_account_info().lamports.borrow() >= earned,
         Errors::InsufficientFunds
     );
-    **vault_account.to_account_info().try_borrow_mut_lamports()? -= host_machine.earned;
-    **host.to_account_info().try_borrow_mut_lamports()? += host_machine.earned;
+    **vault_account.to_account_info().try_borrow_mut_lamports()? -= earned;
+    **ctx.accounts.payout_wallet.to_account_info().try_borrow_mut_lamports()? += earned;
+    vault_account.release_liability(earned);
     host_machine.earned = 0;
 
-    msg!("Claimed {} rewards for host {}", host_machine.earned, host.key());
+    msg!("Claimed {} rewards for host {}", earned, host.key());
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(id: String, _secret_key: String)]
+#[instruction(id: String)]
 pub struct ClaimRewards<'info> {
     #[account(mut)]
     pub host: Signer<'info>,
@@ -55,16 +54,24 @@ pub struct ClaimRewards<'info> {
         bump
     )]
     pub host_machine: Account<'info, HostMachineRegistration>,
-
-    /// CHECK: Admin must be the owner of the vault account
-    pub admin: UncheckedAccount<'info>,
+    /// CHECK: Rewards destination chosen by the host owner
+    #[account(
+        mut,
+        address = host_machine.payout_wallet @ DepinErrors::PayoutWalletMismatch,
+    )]
+    pub payout_wallet: UncheckedAccount<'info>,
 
     #[account(
         mut,
-        seeds = [b"vault_account", admin.key().as_ref(), _secret_key.as_bytes()],
+        seeds = [b"vault", &[VaultKind::RewardsPool as u8]],
         bump = vault_account.bump,
-        constraint = vault_account.owner == admin.key() @ Errors::Unauthorized,
     )]
     pub vault_account: Account<'info, VaultAccount>,
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Depin) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
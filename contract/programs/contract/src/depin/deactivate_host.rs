@@ -1,5 +1,5 @@
 use::anchor_lang::prelude::*;
-use crate::{constants::ADMIN_PUBKEY, errors::DepinErrors, state::HostMachineRegistration};
+use crate::{errors::{DepinErrors, Errors}, state::{HostMachineRegistration, HostStatus, PauseCategory, ProgramConfig, VaultAccount, VaultKind}};
 
 pub fn deactivate_host(
     ctx: Context<DeactivateHost>,
@@ -10,7 +10,7 @@ pub fn deactivate_host(
     let user = &ctx.accounts.user;
 
     require!(
-        user.key() == host.key() || user.key() == ADMIN_PUBKEY,
+        host_machine.is_operated_by(&user.key()) || user.key() == ctx.accounts.config.admin,
         DepinErrors::UnauthorizedAdmin
     );
 
@@ -19,27 +19,32 @@ pub fn deactivate_host(
         DepinErrors::HostKeyMismatch
     );
     require!(
-        host_machine.is_active == true,
+        host_machine.status == HostStatus::Online,
         DepinErrors::HostMachineRegistrationNotActive
     );
     require!(
         host_machine.id == id,
         DepinErrors::InvalidHostMachineRegistrationId
     );
+    require!(
+        !host_machine.occupied,
+        DepinErrors::HostMachineOccupied
+    );
     require!(
         host_machine.started_at > 0,
         DepinErrors::HostMachineRegistrationNotActiveLongEnough
     );
-    host_machine.is_active = false;
+    host_machine.status.transition(HostStatus::Offline)?;
     let timestamp = Clock::get()?.unix_timestamp;
     require!(
         timestamp - host_machine.started_at >= 0,
         DepinErrors::HostMachineRegistrationNotActiveLongEnough
     );
-    let time = (timestamp - host_machine.started_at) as u64;
-    let reward = (time / 3600) * host_machine.sol_per_hour;
-    host_machine.earned += reward as u64;
-    host_machine.started_at = 0; 
+    // Only intervals proven by heartbeats earn rewards; deactivating counts as the final one
+    host_machine.record_heartbeat(timestamp, ctx.accounts.config.max_heartbeat_gap)?;
+    let reward = host_machine.accrue_rewards()?;
+    ctx.accounts.vault_account.add_liability(reward)?;
+    host_machine.started_at = 0;
     msg!("Host machine {} deactivated. Earned: {}", id, host_machine.earned);
     Ok(())
 }
@@ -59,5 +64,19 @@ pub struct DeactivateHost<'info> {
         bump
     )]
     pub host_machine: Account<'info, HostMachineRegistration>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", &[VaultKind::RewardsPool as u8]],
+        bump = vault_account.bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Depin) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
     pub system_program: Program<'info, System>
 }
\ No newline at end of file
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::{DepinErrors, Errors}, state::{HostMachineRegistration, HostStatus, OperatorRole, PauseCategory, ProgramConfig, Role}};
+
+pub fn host_heartbeat(ctx: Context<HostHeartbeat>, id: String) -> Result<()> {
+    let host_machine = &mut ctx.accounts.host_machine;
+
+    // The host's operator pings for itself; anyone else needs the pinger role
+    require!(
+        host_machine.is_operated_by(&ctx.accounts.pinger.key()) || ctx.accounts.operator_role.is_some(),
+        DepinErrors::UnauthorizedHostMachineAccess
+    );
+    require!(
+        host_machine.id == id,
+        DepinErrors::InvalidHostMachineRegistrationId
+    );
+    require!(
+        host_machine.status == HostStatus::Online,
+        DepinErrors::HostMachineRegistrationNotActive
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let credited = host_machine.record_heartbeat(now, ctx.accounts.config.max_heartbeat_gap)?;
+
+    msg!("Heartbeat for host {}: {}s credited, {}s covered", id, credited, host_machine.covered_seconds);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(id: String)]
+pub struct HostHeartbeat<'info> {
+    pub pinger: Signer<'info>,
+
+    #[account(
+        seeds = [b"operator_role", pinger.key().as_ref(), &[Role::Pinger as u8]],
+        bump = operator_role.bump,
+    )]
+    pub operator_role: Option<Account<'info, OperatorRole>>,
+
+    ///CHECK: This account must be the host's key
+    pub host: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"host_machine", host.key().as_ref(), id.as_bytes()],
+        bump
+    )]
+    pub host_machine: Account<'info, HostMachineRegistration>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Depin) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
-use crate::constants::ADMIN_PUBKEY;
 use crate::state::host_machine_registration::HostMachineRegistration;
-use crate::{errors::DepinErrors};
+use crate::state::{HostStatus, OperatorRole, PauseCategory, ProgramConfig, Role};
+use crate::errors::{DepinErrors, Errors};
 
 pub fn initialise_host_registration(    
     ctx: Context<InitialiseHostRegistration>,
@@ -12,14 +12,7 @@ pub fn initialise_host_registration(
     disk_size: u64,
     sol_per_hour: u64,
 ) -> Result<()> {
-    require!(
-        ctx.accounts.admin.key() == ADMIN_PUBKEY,
-        DepinErrors::UnauthorizedAdmin
-    );
-    require!(
-        id.len() <= 32,
-        DepinErrors::InvalidHostMachineRegistrationData
-    );
+    HostMachineRegistration::validate_fields(&id, &host_name, &machine_type, &os)?;
     require!(
         disk_size > 0,
         DepinErrors::InvalidHostMachineRegistrationDiskSize
@@ -34,39 +27,53 @@ pub fn initialise_host_registration(
         host_machine_registration.key() == host_macehine_key,
         DepinErrors::KeyDoesNotMatchPDA
     );
-    host_machine_registration.id = id.clone();
-    host_machine_registration.host_key = user_key;
-    host_machine_registration.is_active = false;
-    host_machine_registration.host_name = host_name;
-    host_machine_registration.machine_type = machine_type;
-    host_machine_registration.os = os;
-    host_machine_registration.disk_size = disk_size;
-    host_machine_registration.bump = bump;
-    host_machine_registration.earned = 0;
-    host_machine_registration.started_at = 0;
-    host_machine_registration.penalized = false;
-    host_machine_registration.sol_per_hour = sol_per_hour;
+    host_machine_registration.set_inner(HostMachineRegistration {
+        status: HostStatus::Registered,
+        host_key: user_key,
+        host_name,
+        machine_type,
+        os,
+        disk_size,
+        bump,
+        id: id.clone(),
+        sol_per_hour,
+        operator: user_key,
+        payout_wallet: user_key,
+        ..Default::default()
+    });
 
     msg!("Initialised host registration for ID: {}", id);
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(id: String)]
+#[instruction(id: String, host_name: String, machine_type: String, os: String)]
 pub struct InitialiseHostRegistration<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub registrar: Signer<'info>,
+
+    #[account(
+        seeds = [b"operator_role", registrar.key().as_ref(), &[Role::HostRegistrar as u8]],
+        bump = operator_role.bump,
+    )]
+    pub operator_role: Account<'info, OperatorRole>,
 
     /// CHECK: This is a user key that will be used to register the host machine.
     pub user_key: UncheckedAccount<'info>,
 
     #[account(
         init,
-        payer = admin,
-        space = HostMachineRegistration::SIZE,
+        payer = registrar,
+        space = HostMachineRegistration::space(&id, &host_name, &machine_type, &os),
         seeds = [b"host_machine", user_key.key().as_ref(), id.as_bytes()],
         bump
     )]
     pub host_machine_registration: Account<'info, HostMachineRegistration>,
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Depin) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
@@ -1,11 +1,39 @@
 pub mod initialise_host_registration;
+pub mod register_host;
+pub mod approve_host;
+pub mod reject_host;
 pub mod activate_host;
 pub mod deactivate_host;
+pub mod host_heartbeat;
+pub mod set_host_keys;
+pub mod update_host;
+pub mod checkpoint_rewards;
 pub mod claim_rewards;
-pub mod penalize_host;
+pub mod slash_host;
+pub mod appeal_slash;
+pub mod reinstate_host;
+pub mod reverse_slash;
+pub mod settle_slash;
+pub mod stake_host;
+pub mod request_unstake;
+pub mod withdraw_stake;
 
 pub use initialise_host_registration::*;
+pub use register_host::*;
+pub use approve_host::*;
+pub use reject_host::*;
 pub use activate_host::*;
 pub use deactivate_host::*;
+pub use host_heartbeat::*;
+pub use set_host_keys::*;
+pub use update_host::*;
+pub use checkpoint_rewards::*;
 pub use claim_rewards::*;
-pub use penalize_host::*;
\ No newline at end of file
+pub use slash_host::*;
+pub use appeal_slash::*;
+pub use reinstate_host::*;
+pub use reverse_slash::*;
+pub use settle_slash::*;
+pub use stake_host::*;
+pub use request_unstake::*;
+pub use withdraw_stake::*;
\ No newline at end of file
@@ -0,0 +1,94 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{errors::{DepinErrors, Errors}, state::{HostMachineRegistration, HostStake, HostStatus, MachineTier, PauseCategory, ProgramConfig}};
+
+pub fn register_host(
+    ctx: Context<RegisterHost>,
+    id: String,
+    host_name: String,
+    machine_type: String,
+    os: String,
+    disk_size: u64,
+    sol_per_hour: u64,
+) -> Result<()> {
+    HostMachineRegistration::validate_fields(&id, &host_name, &machine_type, &os)?;
+    require!(
+        disk_size > 0,
+        DepinErrors::InvalidHostMachineRegistrationDiskSize
+    );
+    let host_key = ctx.accounts.host.key();
+
+    // The host pays the rent and posts its tier's minimum stake, then waits in Pending until a host registrar approves it
+    ctx.accounts.host_machine.set_inner(HostMachineRegistration {
+        status: HostStatus::Pending,
+        host_key,
+        host_name,
+        machine_type,
+        os,
+        disk_size,
+        bump: ctx.bumps.host_machine,
+        id: id.clone(),
+        sol_per_hour,
+        operator: host_key,
+        payout_wallet: host_key,
+        ..Default::default()
+    });
+
+    let stake_amount = ctx.accounts.machine_tier.min_stake;
+    let cpi_context = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+            from: ctx.accounts.host.to_account_info(),
+            to: ctx.accounts.host_stake.to_account_info(),
+        },
+    );
+    system_program::transfer(cpi_context, stake_amount)?;
+
+    let host_stake = &mut ctx.accounts.host_stake;
+    host_stake.host_machine = ctx.accounts.host_machine.key();
+    host_stake.owner = host_key;
+    host_stake.amount = stake_amount;
+    host_stake.bump = ctx.bumps.host_stake;
+
+    msg!("Host registration {} submitted for approval with {} lamports staked", id, stake_amount);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(id: String, host_name: String, machine_type: String, os: String)]
+pub struct RegisterHost<'info> {
+    #[account(mut)]
+    pub host: Signer<'info>,
+
+    #[account(
+        init,
+        payer = host,
+        space = HostMachineRegistration::space(&id, &host_name, &machine_type, &os),
+        seeds = [b"host_machine", host.key().as_ref(), id.as_bytes()],
+        bump
+    )]
+    pub host_machine: Account<'info, HostMachineRegistration>,
+
+    #[account(
+        init,
+        payer = host,
+        space = 8 + HostStake::SIZE,
+        seeds = [b"host_stake", host_machine.key().as_ref()],
+        bump
+    )]
+    pub host_stake: Account<'info, HostStake>,
+
+    #[account(
+        seeds = [b"machine_tier", machine_type.as_bytes(), Pubkey::default().as_ref()],
+        bump = machine_tier.bump,
+    )]
+    pub machine_tier: Account<'info, MachineTier>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Depin) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    pub system_program: Program<'info, System>,
+}
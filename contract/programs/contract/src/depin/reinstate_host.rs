@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::{DepinErrors, Errors}, events::HostReinstated, state::{HostMachineRegistration, HostStatus, OperatorRole, PauseCategory, ProgramConfig, Role}};
+
+pub fn reinstate_host(ctx: Context<ReinstateHost>, id: String) -> Result<()> {
+    let host_machine = &mut ctx.accounts.host_machine;
+    require!(host_machine.status == HostStatus::Penalized, DepinErrors::HostMachineNotPenalized);
+
+    // Slashed funds are returned per record through reverse_slash; this only lifts the penalty
+    host_machine.status.transition(HostStatus::Offline)?;
+
+    emit!(HostReinstated {
+        host_machine: host_machine.key(),
+        arbiter: ctx.accounts.arbiter.key(),
+    });
+
+    msg!("Host {} reinstated", id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(id: String)]
+pub struct ReinstateHost<'info> {
+    pub arbiter: Signer<'info>,
+    #[account(
+        seeds = [b"operator_role", arbiter.key().as_ref(), &[Role::Arbiter as u8]],
+        bump = operator_role.bump,
+    )]
+    pub operator_role: Account<'info, OperatorRole>,
+    /// CHECK: User public key
+    pub user: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"host_machine", user.key().as_ref(), id.as_bytes()],
+        bump
+    )]
+    pub host_machine: Account<'info, HostMachineRegistration>,
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Depin) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::{DepinErrors, Errors}, state::{HostMachineRegistration, HostStake, HostStatus, OperatorRole, PauseCategory, ProgramConfig, Role}};
+
+// Closing the registration and the stake posted with it refunds the host's rent and collateral.
+pub fn reject_host(_ctx: Context<RejectHost>, id: String) -> Result<()> {
+    msg!("Host registration {} rejected", id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(id: String)]
+pub struct RejectHost<'info> {
+    pub registrar: Signer<'info>,
+
+    #[account(
+        seeds = [b"operator_role", registrar.key().as_ref(), &[Role::HostRegistrar as u8]],
+        bump = operator_role.bump,
+    )]
+    pub operator_role: Account<'info, OperatorRole>,
+
+    /// CHECK: Host key the registration was submitted under, receives the refund
+    #[account(mut)]
+    pub host: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = host,
+        seeds = [b"host_machine", host.key().as_ref(), id.as_bytes()],
+        bump = host_machine.bump,
+        constraint = host_machine.status == HostStatus::Pending @ DepinErrors::HostMachineNotPending,
+    )]
+    pub host_machine: Account<'info, HostMachineRegistration>,
+
+    #[account(
+        mut,
+        close = host,
+        seeds = [b"host_stake", host_machine.key().as_ref()],
+        bump = host_stake.bump,
+    )]
+    pub host_stake: Account<'info, HostStake>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Depin) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::{DepinErrors, Errors}, state::{HostMachineRegistration, HostStake, HostStatus, PauseCategory, ProgramConfig}};
+
+pub fn request_unstake(ctx: Context<RequestUnstake>, id: String, amount: u64) -> Result<()> {
+    require!(amount > 0, Errors::InvalidAmount);
+    let host_machine = &ctx.accounts.host_machine;
+    require!(host_machine.id == id, DepinErrors::InvalidHostMachineRegistrationId);
+    // Collateral stays locked while it backs a live host or a pending penalty
+    require!(
+        host_machine.status != HostStatus::Online && host_machine.status != HostStatus::Penalized,
+        DepinErrors::StakeLocked
+    );
+
+    let host_stake = &mut ctx.accounts.host_stake;
+    require!(host_stake.amount >= amount, DepinErrors::InsufficientStake);
+    host_stake.amount -= amount;
+    host_stake.unbonding_amount = host_stake.unbonding_amount.checked_add(amount)
+        .ok_or(Errors::ArithmeticOverflow)?;
+    host_stake.unbonding_at = Clock::get()?.unix_timestamp
+        .checked_add(ctx.accounts.config.unbonding_period)
+        .ok_or(Errors::ArithmeticOverflow)?;
+
+    msg!("Host {} unbonding {} lamports until {}", id, amount, host_stake.unbonding_at);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(id: String)]
+pub struct RequestUnstake<'info> {
+    pub host: Signer<'info>,
+
+    #[account(
+        seeds = [b"host_machine", host.key().as_ref(), id.as_bytes()],
+        bump = host_machine.bump,
+    )]
+    pub host_machine: Account<'info, HostMachineRegistration>,
+
+    #[account(
+        mut,
+        seeds = [b"host_stake", host_machine.key().as_ref()],
+        bump = host_stake.bump,
+    )]
+    pub host_stake: Account<'info, HostStake>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Depin) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::{DepinErrors, Errors}, events::SlashReversed, state::{HostMachineRegistration, HostStake, OperatorRole, PauseCategory, ProgramConfig, Role, SlashRecord, VaultAccount, VaultKind}};
+
+pub fn reverse_slash(ctx: Context<ReverseSlash>, id: String, index: u64) -> Result<()> {
+    let host_machine = &mut ctx.accounts.host_machine;
+    let record = &mut ctx.accounts.slash_record;
+    require!(!record.reversed, DepinErrors::SlashAlreadyReversed);
+    require!(!record.settled, DepinErrors::SlashAlreadySettled);
+
+    let earnings_restored = record.earnings_slashed;
+    let stake_restored = record.stake_slashed;
+    let total = earnings_restored
+        .checked_add(stake_restored)
+        .ok_or(Errors::ArithmeticOverflow)?;
+    ctx.accounts.insurance_vault.release_liability(total);
+    let insurance_vault = &ctx.accounts.insurance_vault;
+    require!(
+        insurance_vault.free_balance(insurance_vault.to_account_info().lamports())? >= total,
+        Errors::ExceedsFreeBalance
+    );
+
+    // Restored earnings are owed to the host again, so the rewards pool takes the liability back
+    **ctx.accounts.insurance_vault.to_account_info().try_borrow_mut_lamports()? -= earnings_restored;
+    **ctx.accounts.rewards_vault.to_account_info().try_borrow_mut_lamports()? += earnings_restored;
+    ctx.accounts.rewards_vault.add_liability(earnings_restored)?;
+    host_machine.earned = host_machine.earned
+        .checked_add(earnings_restored)
+        .ok_or(Errors::ArithmeticOverflow)?;
+
+    // Collateral goes back to where it was taken from, so slashed unbonding stays withdrawable
+    let host_stake = &mut ctx.accounts.host_stake;
+    **ctx.accounts.insurance_vault.to_account_info().try_borrow_mut_lamports()? -= stake_restored;
+    **host_stake.to_account_info().try_borrow_mut_lamports()? += stake_restored;
+    host_stake.amount = host_stake.amount
+        .checked_add(stake_restored - record.unbonding_slashed)
+        .ok_or(Errors::ArithmeticOverflow)?;
+    host_stake.unbonding_amount = host_stake.unbonding_amount
+        .checked_add(record.unbonding_slashed)
+        .ok_or(Errors::ArithmeticOverflow)?;
+
+    host_machine.total_slashed = host_machine.total_slashed.saturating_sub(total);
+    record.reversed = true;
+    record.settled = true;
+
+    emit!(SlashReversed {
+        host_machine: host_machine.key(),
+        index,
+        arbiter: ctx.accounts.arbiter.key(),
+        earnings_restored,
+        stake_restored,
+    });
+
+    msg!("Slash #{} of host {} reversed", index, id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(id: String, index: u64)]
+pub struct ReverseSlash<'info> {
+    pub arbiter: Signer<'info>,
+    #[account(
+        seeds = [b"operator_role", arbiter.key().as_ref(), &[Role::Arbiter as u8]],
+        bump = operator_role.bump,
+    )]
+    pub operator_role: Account<'info, OperatorRole>,
+    /// CHECK: User public key
+    pub user: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"host_machine", user.key().as_ref(), id.as_bytes()],
+        bump
+    )]
+    pub host_machine: Account<'info, HostMachineRegistration>,
+    #[account(
+        mut,
+        seeds = [b"host_stake", host_machine.key().as_ref()],
+        bump = host_stake.bump,
+    )]
+    pub host_stake: Account<'info, HostStake>,
+    #[account(
+        mut,
+        seeds = [b"slash_record".as_ref(), host_machine.key().as_ref(), &index.to_le_bytes()],
+        bump = slash_record.bump,
+    )]
+    pub slash_record: Account<'info, SlashRecord>,
+    #[account(
+        mut,
+        seeds = [b"vault", &[VaultKind::RewardsPool as u8]],
+        bump = rewards_vault.bump,
+    )]
+    pub rewards_vault: Account<'info, VaultAccount>,
+    #[account(
+        mut,
+        seeds = [b"vault", &[VaultKind::Insurance as u8]],
+        bump = insurance_vault.bump,
+    )]
+    pub insurance_vault: Account<'info, VaultAccount>,
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Depin) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::{DepinErrors, Errors}, state::{HostMachineRegistration, PauseCategory, ProgramConfig}};
+
+pub fn set_host_keys(
+    ctx: Context<SetHostKeys>,
+    id: String,
+    operator: Pubkey,
+    payout_wallet: Pubkey,
+) -> Result<()> {
+    let host_machine = &mut ctx.accounts.host_machine;
+    require!(host_machine.id == id, DepinErrors::InvalidHostMachineRegistrationId);
+    require!(
+        operator != Pubkey::default() && payout_wallet != Pubkey::default(),
+        DepinErrors::InvalidHostMachineRegistrationData
+    );
+
+    host_machine.operator = operator;
+    host_machine.payout_wallet = payout_wallet;
+
+    msg!("Host {} operator set to {}, payouts to {}", id, operator, payout_wallet);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(id: String)]
+pub struct SetHostKeys<'info> {
+    // Only the owner key that seeds the registration can rotate its operational keys
+    pub host: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"host_machine", host.key().as_ref(), id.as_bytes()],
+        bump = host_machine.bump,
+        constraint = host_machine.host_key == host.key() @ DepinErrors::HostKeyMismatch,
+    )]
+    pub host_machine: Account<'info, HostMachineRegistration>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Depin) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::{DepinErrors, Errors}, events::SlashSettled, state::{HostMachineRegistration, OperatorRole, PauseCategory, ProgramConfig, Role, SlashRecord, VaultAccount, VaultKind}};
+
+pub fn settle_slash(ctx: Context<SettleSlash>, id: String, index: u64) -> Result<()> {
+    let record = &mut ctx.accounts.slash_record;
+    require!(!record.settled, DepinErrors::SlashAlreadySettled);
+
+    // Unappealed slashes settle once the window closes, appealed ones only when an arbiter upholds them
+    if record.appeal_hash.is_some() {
+        require!(ctx.accounts.operator_role.is_some(), Errors::Unauthorized);
+    } else {
+        require!(
+            Clock::get()?.unix_timestamp > record.created_at.saturating_add(ctx.accounts.config.appeal_window),
+            DepinErrors::SlashAppealOpen
+        );
+    }
+
+    let released = record.stake_slashed
+        .checked_add(record.earnings_slashed)
+        .ok_or(Errors::ArithmeticOverflow)?;
+    ctx.accounts.insurance_vault.release_liability(released);
+    record.settled = true;
+
+    emit!(SlashSettled {
+        host_machine: ctx.accounts.host_machine.key(),
+        index,
+        released,
+    });
+
+    msg!("Slash #{} of host {} settled, {} lamports released to insurance", index, id, released);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(id: String, index: u64)]
+pub struct SettleSlash<'info> {
+    pub caller: Signer<'info>,
+    #[account(
+        seeds = [b"operator_role", caller.key().as_ref(), &[Role::Arbiter as u8]],
+        bump = operator_role.bump,
+    )]
+    pub operator_role: Option<Account<'info, OperatorRole>>,
+    /// CHECK: User public key
+    pub user: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"host_machine", user.key().as_ref(), id.as_bytes()],
+        bump
+    )]
+    pub host_machine: Account<'info, HostMachineRegistration>,
+    #[account(
+        mut,
+        seeds = [b"slash_record".as_ref(), host_machine.key().as_ref(), &index.to_le_bytes()],
+        bump = slash_record.bump,
+    )]
+    pub slash_record: Account<'info, SlashRecord>,
+    #[account(
+        mut,
+        seeds = [b"vault", &[VaultKind::Insurance as u8]],
+        bump = insurance_vault.bump,
+    )]
+    pub insurance_vault: Account<'info, VaultAccount>,
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Depin) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
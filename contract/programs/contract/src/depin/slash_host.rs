@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::BPS_DENOMINATOR, errors::{DepinErrors, Errors}, events::HostSlashed, state::{apply_bps, HostMachineRegistration, HostStake, HostStatus, OperatorRole, PauseCategory, ProgramConfig, Role, SlashRecord, VaultAccount, VaultKind}};
+
+pub fn slash_host(
+    ctx: Context<SlashHost>,
+    id: String,
+    slash_bps: u16,
+    evidence_hash: [u8; 32],
+) -> Result<()> {
+    require!(
+        slash_bps > 0 && slash_bps as u64 <= BPS_DENOMINATOR,
+        DepinErrors::InvalidSlash
+    );
+    let host_machine = &mut ctx.accounts.host_machine;
+
+    // Slashed earnings are no longer owed to the host, so they leave the rewards pool for the insurance fund
+    let earnings_slashed = apply_bps(host_machine.earned, slash_bps);
+    require!(
+        **ctx.accounts.rewards_vault.to_account_info().lamports.borrow() >= earnings_slashed,
+        Errors::InsufficientFunds
+    );
+    host_machine.earned -= earnings_slashed;
+    ctx.accounts.rewards_vault.release_liability(earnings_slashed);
+    **ctx.accounts.rewards_vault.to_account_info().try_borrow_mut_lamports()? -= earnings_slashed;
+    **ctx.accounts.insurance_vault.to_account_info().try_borrow_mut_lamports()? += earnings_slashed;
+
+    // Unbonding collateral is still at stake until it is withdrawn
+    let host_stake = &mut ctx.accounts.host_stake;
+    let from_stake = apply_bps(host_stake.amount, slash_bps);
+    let from_unbonding = apply_bps(host_stake.unbonding_amount, slash_bps);
+    host_stake.amount -= from_stake;
+    host_stake.unbonding_amount -= from_unbonding;
+    let stake_slashed = from_stake + from_unbonding;
+    **host_stake.to_account_info().try_borrow_mut_lamports()? -= stake_slashed;
+    **ctx.accounts.insurance_vault.to_account_info().try_borrow_mut_lamports()? += stake_slashed;
+
+    // Slashed funds stay owed to the host until the appeal window closes or an arbiter rules on it
+    let total_slashed = stake_slashed
+        .checked_add(earnings_slashed)
+        .ok_or(Errors::ArithmeticOverflow)?;
+    ctx.accounts.insurance_vault.add_liability(total_slashed)?;
+
+    if host_machine.status != HostStatus::Penalized {
+        host_machine.status.transition(HostStatus::Penalized)?;
+    }
+    host_machine.started_at = 0;
+    host_machine.covered_seconds = 0;
+
+    let now = Clock::get()?.unix_timestamp;
+    let record = &mut ctx.accounts.slash_record;
+    record.host_machine = host_machine.key();
+    record.index = host_machine.slash_count;
+    record.slasher = ctx.accounts.slasher.key();
+    record.slash_bps = slash_bps;
+    record.evidence_hash = evidence_hash;
+    record.stake_slashed = stake_slashed;
+    record.unbonding_slashed = from_unbonding;
+    record.earnings_slashed = earnings_slashed;
+    record.created_at = now;
+    record.appeal_hash = None;
+    record.appealed_at = 0;
+    record.reversed = false;
+    record.settled = false;
+    record.bump = ctx.bumps.slash_record;
+
+    host_machine.slash_count = host_machine.slash_count.checked_add(1).ok_or(Errors::ArithmeticOverflow)?;
+    host_machine.total_slashed = host_machine
+        .total_slashed
+        .checked_add(total_slashed)
+        .ok_or(Errors::ArithmeticOverflow)?;
+
+    emit!(HostSlashed {
+        host_machine: host_machine.key(),
+        index: record.index,
+        slash_bps,
+        evidence_hash,
+        stake_slashed,
+        earnings_slashed,
+    });
+
+    msg!("Host {} slashed {} bps: {} stake, {} earnings", id, slash_bps, stake_slashed, earnings_slashed);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(id: String)]
+pub struct SlashHost<'info> {
+    #[account(mut)]
+    pub slasher: Signer<'info>,
+    #[account(
+        seeds = [b"operator_role", slasher.key().as_ref(), &[Role::Slasher as u8]],
+        bump = operator_role.bump,
+    )]
+    pub operator_role: Account<'info, OperatorRole>,
+    /// CHECK: User public key
+    pub user: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"host_machine", user.key().as_ref(), id.as_bytes()],
+        bump
+    )]
+    pub host_machine: Account<'info, HostMachineRegistration>,
+    #[account(
+        mut,
+        seeds = [b"host_stake", host_machine.key().as_ref()],
+        bump = host_stake.bump,
+    )]
+    pub host_stake: Account<'info, HostStake>,
+    #[account(
+        init,
+        payer = slasher,
+        space = 8 + SlashRecord::SIZE,
+        seeds = [b"slash_record".as_ref(), host_machine.key().as_ref(), &host_machine.slash_count.to_le_bytes()],
+        bump
+    )]
+    pub slash_record: Account<'info, SlashRecord>,
+    #[account(
+        mut,
+        seeds = [b"vault", &[VaultKind::RewardsPool as u8]],
+        bump = rewards_vault.bump,
+    )]
+    pub rewards_vault: Account<'info, VaultAccount>,
+    #[account(
+        mut,
+        seeds = [b"vault", &[VaultKind::Insurance as u8]],
+        bump = insurance_vault.bump,
+    )]
+    pub insurance_vault: Account<'info, VaultAccount>,
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Depin) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,59 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{errors::{DepinErrors, Errors}, state::{HostMachineRegistration, HostStake, HostStatus, PauseCategory, ProgramConfig}};
+
+pub fn stake_host(ctx: Context<StakeHost>, id: String, amount: u64) -> Result<()> {
+    require!(amount > 0, Errors::InvalidAmount);
+    let host_machine = &ctx.accounts.host_machine;
+    require!(host_machine.id == id, DepinErrors::InvalidHostMachineRegistrationId);
+    require!(host_machine.status != HostStatus::Retired, Errors::InvalidStatusTransition);
+
+    let cpi_context = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+            from: ctx.accounts.host.to_account_info(),
+            to: ctx.accounts.host_stake.to_account_info(),
+        },
+    );
+    system_program::transfer(cpi_context, amount)?;
+
+    let host_stake = &mut ctx.accounts.host_stake;
+    host_stake.host_machine = host_machine.key();
+    host_stake.owner = ctx.accounts.host.key();
+    host_stake.amount = host_stake.amount.checked_add(amount)
+        .ok_or(Errors::ArithmeticOverflow)?;
+    host_stake.bump = ctx.bumps.host_stake;
+
+    msg!("Host {} staked {} lamports, total stake: {}", id, amount, host_stake.amount);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(id: String)]
+pub struct StakeHost<'info> {
+    #[account(mut)]
+    pub host: Signer<'info>,
+
+    #[account(
+        seeds = [b"host_machine", host.key().as_ref(), id.as_bytes()],
+        bump = host_machine.bump,
+    )]
+    pub host_machine: Account<'info, HostMachineRegistration>,
+
+    #[account(
+        init_if_needed,
+        payer = host,
+        space = 8 + HostStake::SIZE,
+        seeds = [b"host_stake", host_machine.key().as_ref()],
+        bump
+    )]
+    pub host_stake: Account<'info, HostStake>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Depin) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    pub system_program: Program<'info, System>,
+}
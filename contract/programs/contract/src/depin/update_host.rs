@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::{DepinErrors, Errors}, events::HostUpdated, state::{HostMachineRegistration, HostStatus, PauseCategory, ProgramConfig}};
+
+pub fn update_host(
+    ctx: Context<UpdateHost>,
+    id: String,
+    host_name: String,
+    machine_type: String,
+    os: String,
+    disk_size: u64,
+    sol_per_hour: u64,
+) -> Result<()> {
+    let host_machine = &mut ctx.accounts.host_machine;
+    require!(
+        matches!(host_machine.status, HostStatus::Pending | HostStatus::Registered | HostStatus::Offline),
+        DepinErrors::HostMachineMustBeOffline
+    );
+    HostMachineRegistration::validate_fields(&id, &host_name, &machine_type, &os)?;
+    require!(disk_size > 0, DepinErrors::InvalidHostMachineRegistrationDiskSize);
+
+    // The reward rate and machine tier decide what the rewards pool pays out, so the admin co-signs those
+    if sol_per_hour != host_machine.sol_per_hour || machine_type != host_machine.machine_type {
+        let admin = ctx.accounts.admin.as_ref().ok_or(Errors::Unauthorized)?;
+        require!(admin.key() == ctx.accounts.config.admin, Errors::Unauthorized);
+    }
+
+    host_machine.host_name = host_name;
+    host_machine.machine_type = machine_type;
+    host_machine.os = os;
+    host_machine.disk_size = disk_size;
+    host_machine.sol_per_hour = sol_per_hour;
+
+    emit!(HostUpdated {
+        host_machine: host_machine.key(),
+        host_key: host_machine.host_key,
+        id: id.clone(),
+        host_name: host_machine.host_name.clone(),
+        machine_type: host_machine.machine_type.clone(),
+        os: host_machine.os.clone(),
+        disk_size,
+        sol_per_hour,
+    });
+
+    msg!("Host machine {} updated", id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(id: String, host_name: String, machine_type: String, os: String)]
+pub struct UpdateHost<'info> {
+    #[account(mut)]
+    pub host: Signer<'info>,
+
+    pub admin: Option<Signer<'info>>,
+
+    #[account(
+        mut,
+        seeds = [b"host_machine", host.key().as_ref(), id.as_bytes()],
+        bump = host_machine.bump,
+        constraint = host_machine.host_key == host.key() @ DepinErrors::HostKeyMismatch,
+        realloc = HostMachineRegistration::space(&id, &host_name, &machine_type, &os),
+        realloc::payer = host,
+        realloc::zero = false,
+    )]
+    pub host_machine: Account<'info, HostMachineRegistration>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Depin) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::{DepinErrors, Errors}, state::{HostMachineRegistration, HostStake, HostStatus, PauseCategory, ProgramConfig}};
+
+pub fn withdraw_stake(ctx: Context<WithdrawStake>, id: String) -> Result<()> {
+    let host_machine = &ctx.accounts.host_machine;
+    require!(host_machine.id == id, DepinErrors::InvalidHostMachineRegistrationId);
+    require!(host_machine.status != HostStatus::Penalized, DepinErrors::StakeLocked);
+
+    let host_stake = &mut ctx.accounts.host_stake;
+    let amount = host_stake.unbonding_amount;
+    require!(amount > 0, DepinErrors::NothingToWithdraw);
+    require!(Clock::get()?.unix_timestamp >= host_stake.unbonding_at, DepinErrors::StakeUnbonding);
+
+    **host_stake.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.host.to_account_info().try_borrow_mut_lamports()? += amount;
+    host_stake.unbonding_amount = 0;
+
+    msg!("Host {} withdrew {} lamports of stake", id, amount);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(id: String)]
+pub struct WithdrawStake<'info> {
+    #[account(mut)]
+    pub host: Signer<'info>,
+
+    #[account(
+        seeds = [b"host_machine", host.key().as_ref(), id.as_bytes()],
+        bump = host_machine.bump,
+    )]
+    pub host_machine: Account<'info, HostMachineRegistration>,
+
+    #[account(
+        mut,
+        seeds = [b"host_stake", host_machine.key().as_ref()],
+        bump = host_stake.bump,
+    )]
+    pub host_stake: Account<'info, HostStake>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Depin) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
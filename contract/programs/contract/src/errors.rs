@@ -28,6 +28,50 @@ pub enum Errors {
     NotActive,
     #[msg("Arithmetic overflow occurred during operation")]
     ArithmeticOverflow,
+    #[msg("No admin handover is pending")]
+    NoPendingAdmin,
+    #[msg("Program is paused for this operation")]
+    ProgramPaused,
+    #[msg("Account is not a legacy vault account")]
+    InvalidLegacyVault,
+    #[msg("Invalid treasury policy")]
+    InvalidTreasuryPolicy,
+    #[msg("Withdrawal exceeds the direct withdrawal limit for this window, propose it instead")]
+    WithdrawalAboveLimit,
+    #[msg("Signer is not a treasury policy signer")]
+    NotTreasurySigner,
+    #[msg("Withdrawal proposal is not pending")]
+    ProposalNotPending,
+    #[msg("Withdrawal proposal already approved by this signer")]
+    AlreadyApproved,
+    #[msg("Withdrawal proposal has not reached the approval threshold")]
+    ThresholdNotMet,
+    #[msg("Withdrawal proposal timelock has not elapsed")]
+    TimelockNotElapsed,
+    #[msg("Amount exceeds the vault balance left after liabilities and rent")]
+    ExceedsFreeBalance,
+    #[msg("Fee basis points exceed 100%")]
+    InvalidFeeConfig,
+    #[msg("Referrer account does not match the escrow session")]
+    ReferrerMismatch,
+    #[msg("Host machine does not match the rental session")]
+    HostMachineMismatch,
+    #[msg("Invalid machine tier")]
+    InvalidMachineTier,
+    #[msg("Machine tier does not match the host machine type")]
+    MachineTierMismatch,
+    #[msg("Rental has not reached its end time yet")]
+    RentalNotExpired,
+    #[msg("Status transition is not allowed")]
+    InvalidStatusTransition,
+    #[msg("Invalid allowed mint list")]
+    InvalidMintList,
+    #[msg("Mint is not allowed for payments")]
+    MintNotAllowed,
+    #[msg("Session is settled in a different mint")]
+    PaymentMintMismatch,
+    #[msg("Renters can't refer themselves")]
+    SelfReferral,
 }
 
 #[error_code]
@@ -66,4 +110,34 @@ pub enum DepinErrors {
     HostMachinePenalized,
     #[msg("Host machine should not be active for claiming earned rewards")]
     HostMachineShouldNotBeActiveForClaiming,
+    #[msg("Host machine is currently serving a rental")]
+    HostMachineOccupied,
+    #[msg("Host stake is below the minimum for its machine type")]
+    InsufficientStake,
+    #[msg("Stake is locked while the host is online or penalized")]
+    StakeLocked,
+    #[msg("Stake is still unbonding")]
+    StakeUnbonding,
+    #[msg("No unbonded stake to withdraw")]
+    NothingToWithdraw,
+    #[msg("Slash percentage must be between 1 and 10000 bps")]
+    InvalidSlash,
+    #[msg("Host machine is not penalized")]
+    HostMachineNotPenalized,
+    #[msg("Slash has already been appealed")]
+    SlashAlreadyAppealed,
+    #[msg("Appeal window for this slash has closed")]
+    AppealWindowClosed,
+    #[msg("Slashed funds have already been restored")]
+    SlashAlreadyReversed,
+    #[msg("Payout wallet does not match the host registration")]
+    PayoutWalletMismatch,
+    #[msg("Host machine registration is not pending approval")]
+    HostMachineNotPending,
+    #[msg("Host machine must be offline to update")]
+    HostMachineMustBeOffline,
+    #[msg("Slashed funds have already been settled")]
+    SlashAlreadySettled,
+    #[msg("Slash can't be settled while its appeal is open")]
+    SlashAppealOpen,
 }
\ No newline at end of file
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct RentalSettled {
+    pub user: Pubkey,
+    pub id: String,
+    pub refunded: u64,
+    pub host_payout: u64,
+    pub protocol_fee: u64,
+    pub referrer_fee: u64,
+    pub referrer: Option<Pubkey>,
+    pub host_machine: Option<Pubkey>,
+}
+
+#[event]
+pub struct RentalExpired {
+    pub user: Pubkey,
+    pub id: String,
+    pub end_time: i64,
+    pub caller: Pubkey,
+    pub bounty: u64,
+}
+
+#[event]
+pub struct HostSlashed {
+    pub host_machine: Pubkey,
+    pub index: u64,
+    pub slash_bps: u16,
+    pub evidence_hash: [u8; 32],
+    pub stake_slashed: u64,
+    pub earnings_slashed: u64,
+}
+
+#[event]
+pub struct SlashAppealed {
+    pub host_machine: Pubkey,
+    pub index: u64,
+    pub counter_evidence_hash: [u8; 32],
+}
+
+#[event]
+pub struct SlashSettled {
+    pub host_machine: Pubkey,
+    pub index: u64,
+    pub released: u64,
+}
+
+#[event]
+pub struct SlashReversed {
+    pub host_machine: Pubkey,
+    pub index: u64,
+    pub arbiter: Pubkey,
+    pub earnings_restored: u64,
+    pub stake_restored: u64,
+}
+
+#[event]
+pub struct HostReinstated {
+    pub host_machine: Pubkey,
+    pub arbiter: Pubkey,
+}
+
+#[event]
+pub struct HostUpdated {
+    pub host_machine: Pubkey,
+    pub host_key: Pubkey,
+    pub id: String,
+    pub host_name: String,
+    pub machine_type: String,
+    pub os: String,
+    pub disk_size: u64,
+    pub sol_per_hour: u64,
+}
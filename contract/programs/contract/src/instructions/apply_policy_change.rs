@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::Errors, state::{PauseCategory, ProgramConfig, TreasuryPolicy}};
+
+pub fn apply_policy_change(ctx: Context<ApplyPolicyChange>) -> Result<()> {
+    let policy = &mut ctx.accounts.treasury_policy;
+    let change = policy.pending_change.clone().ok_or(Errors::ProposalNotPending)?;
+
+    require!(
+        policy.approval_count(&change.approvals) >= policy.threshold as usize,
+        Errors::ThresholdNotMet
+    );
+    require!(
+        Clock::get()?.unix_timestamp >= change.executable_at,
+        Errors::TimelockNotElapsed
+    );
+
+    policy.signers = change.signers;
+    policy.threshold = change.threshold;
+    policy.timelock_seconds = change.timelock_seconds;
+    policy.withdrawal_limit = change.withdrawal_limit;
+    policy.pending_change = None;
+
+    msg!(
+        "Treasury policy applied: {}-of-{}, timelock {}s, limit {} lamports",
+        policy.threshold,
+        policy.signers.len(),
+        policy.timelock_seconds,
+        policy.withdrawal_limit
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApplyPolicyChange<'info> {
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_policy"],
+        bump = treasury_policy.bump,
+    )]
+    pub treasury_policy: Account<'info, TreasuryPolicy>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Vault) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
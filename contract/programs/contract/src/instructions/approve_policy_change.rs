@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::Errors, state::{PauseCategory, ProgramConfig, TreasuryPolicy}};
+
+pub fn approve_policy_change(ctx: Context<ApprovePolicyChange>) -> Result<()> {
+    let policy = &mut ctx.accounts.treasury_policy;
+    let approver = ctx.accounts.approver.key();
+    require!(policy.is_signer(&approver), Errors::NotTreasurySigner);
+
+    let signers = policy.signers.clone();
+    let change = policy.pending_change.as_mut().ok_or(Errors::ProposalNotPending)?;
+    require!(!change.approvals.contains(&approver), Errors::AlreadyApproved);
+
+    change.approvals.retain(|key| signers.contains(key));
+    change.approvals.push(approver);
+
+    msg!("Treasury policy change approved by {} ({}/{})", approver, change.approvals.len(), policy.threshold);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApprovePolicyChange<'info> {
+    pub approver: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_policy"],
+        bump = treasury_policy.bump,
+    )]
+    pub treasury_policy: Account<'info, TreasuryPolicy>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Vault) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
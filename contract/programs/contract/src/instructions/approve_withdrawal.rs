@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::Errors, state::{PauseCategory, ProgramConfig, ProposalStatus, TreasuryPolicy, WithdrawalProposal}};
+
+pub fn approve_withdrawal(ctx: Context<ApproveWithdrawal>, index: u64) -> Result<()> {
+    let policy = &ctx.accounts.treasury_policy;
+    let proposal = &mut ctx.accounts.proposal;
+    let approver = ctx.accounts.approver.key();
+
+    require!(policy.is_signer(&approver), Errors::NotTreasurySigner);
+    require!(proposal.status == ProposalStatus::Pending, Errors::ProposalNotPending);
+    require!(!proposal.approvals.contains(&approver), Errors::AlreadyApproved);
+
+    // Drop approvals from removed signers so the list stays within MAX_SIGNERS after the signer set changes
+    proposal.approvals.retain(|key| policy.is_signer(key));
+    proposal.approvals.push(approver);
+
+    msg!(
+        "Withdrawal {} approved by {} ({}/{})",
+        index,
+        approver,
+        proposal.approval_count(policy),
+        policy.threshold
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(index: u64)]
+pub struct ApproveWithdrawal<'info> {
+    pub approver: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury_policy"],
+        bump = treasury_policy.bump,
+    )]
+    pub treasury_policy: Account<'info, TreasuryPolicy>,
+
+    #[account(
+        mut,
+        seeds = [b"withdrawal_proposal", &index.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, WithdrawalProposal>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Vault) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
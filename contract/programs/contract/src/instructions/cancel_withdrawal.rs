@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::Errors, state::{ProposalStatus, TreasuryPolicy, WithdrawalProposal}};
+
+pub fn cancel_withdrawal(ctx: Context<CancelWithdrawal>, index: u64) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+
+    require!(
+        ctx.accounts.treasury_policy.is_signer(&ctx.accounts.signer.key()),
+        Errors::NotTreasurySigner
+    );
+    require!(proposal.status == ProposalStatus::Pending, Errors::ProposalNotPending);
+
+    proposal.status = ProposalStatus::Cancelled;
+
+    msg!("Withdrawal {} cancelled by {}", index, ctx.accounts.signer.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(index: u64)]
+pub struct CancelWithdrawal<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury_policy"],
+        bump = treasury_policy.bump,
+    )]
+    pub treasury_policy: Account<'info, TreasuryPolicy>,
+
+    #[account(
+        mut,
+        seeds = [b"withdrawal_proposal", &index.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, WithdrawalProposal>,
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::Errors, state::RentalSession};
+
+// Only returns the user's own rent, so it stays available while the program is paused.
+pub fn close_rental_session(ctx: Context<CloseRentalSession>, _id: String) -> Result<()> {
+    let rental_session = &ctx.accounts.rental_session;
+    require!(rental_session.status.is_settled(), Errors::AlreadyActive);
+    require!(rental_session.user == ctx.accounts.user.key(), Errors::Unauthorized);
+
+    msg!("Rental session {} closed for user: {}", _id, ctx.accounts.user.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_id: String)]
+pub struct CloseRentalSession<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"rental_session", user.key().as_ref(), _id.as_bytes()],
+        bump = rental_session.bump,
+    )]
+    pub rental_session: Account<'info, RentalSession>,
+}
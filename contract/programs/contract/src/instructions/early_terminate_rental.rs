@@ -0,0 +1,51 @@
+use anchor_lang::{prelude::*};
+use crate::{errors::Errors, state::{apply_bps, PauseCategory, ProgramConfig, RentalSession, RentalStatus, VaultAccount, VaultKind}}; 
+
+pub fn early_terminate_rental(ctx: Context<EarlyTerminateRental>, _id: String) -> Result<()> {
+    let rental_session = &mut ctx.accounts.rental_session;
+    require!(rental_session.status == RentalStatus::Active, Errors::NotActive);
+    require!(rental_session.user == ctx.accounts.payer.key(), Errors::Unauthorized);
+    require!(rental_session.duration_seconds > 0, Errors::InvalidDuration);
+    require!(rental_session.mint.is_none(), Errors::PaymentMintMismatch);
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(current_time < rental_session.end_time, Errors::RentalExpired);
+
+    // Refund only the unused share of the rental, less the cancellation fee
+    let unused = rental_session.unused_amount(current_time);
+    let cancellation_fee = apply_bps(unused, ctx.accounts.config.cancellation_fee_bps);
+    let refund = unused - cancellation_fee;
+
+    **ctx.accounts.vault_account.to_account_info().try_borrow_mut_lamports()? -= refund;
+    **ctx.accounts.payer.to_account_info().try_borrow_mut_lamports()? += refund;
+
+    ctx.accounts.vault_account.release_liability(rental_session.amount_paid);
+    rental_session.terminate_early(current_time)?;
+    msg!("Rental terminated early, refunded {} lamports (fee {})", refund, cancellation_fee);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_id: String)]
+pub struct EarlyTerminateRental<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", &[VaultKind::RentalTreasury as u8]],
+        bump = vault_account.bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    #[account(
+        mut,
+        seeds = [b"rental_session", payer.key().as_ref(), _id.as_bytes()],
+        bump = rental_session.bump,
+    )]
+    pub rental_session: Account<'info, RentalSession>,
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Rentals) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+
+use crate::{errors::Errors, state::{apply_bps, PauseCategory, ProgramConfig, RentalSession, RentalStatus, TokenLedger, VaultAccount, VaultKind}};
+
+pub fn early_terminate_rental_token(ctx: Context<EarlyTerminateRentalToken>, _id: String) -> Result<()> {
+    let rental_session = &mut ctx.accounts.rental_session;
+    require!(rental_session.status == RentalStatus::Active, Errors::NotActive);
+    require!(rental_session.user == ctx.accounts.payer.key(), Errors::Unauthorized);
+    require!(rental_session.duration_seconds > 0, Errors::InvalidDuration);
+    require!(rental_session.mint == Some(ctx.accounts.mint.key()), Errors::PaymentMintMismatch);
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(current_time < rental_session.end_time, Errors::RentalExpired);
+
+    // Refund only the unused share of the rental, less the cancellation fee
+    let unused = rental_session.unused_amount(current_time);
+    let cancellation_fee = apply_bps(unused, ctx.accounts.config.cancellation_fee_bps);
+    let refund = unused - cancellation_fee;
+
+    let vault_seeds = &[
+        b"vault".as_ref(),
+        &[VaultKind::RentalTreasury as u8],
+        &[ctx.accounts.vault_account.bump],
+    ];
+    let signer_seeds = &[&vault_seeds[..]];
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token_interface::TransferChecked {
+            from: ctx.accounts.treasury_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.payer_token_account.to_account_info(),
+            authority: ctx.accounts.vault_account.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::transfer_checked(cpi_context, refund, ctx.accounts.mint.decimals)?;
+
+    ctx.accounts.token_ledger.release_liability(rental_session.amount_paid);
+    rental_session.terminate_early(current_time)?;
+    msg!("Token rental terminated early, refunded {} (fee {})", refund, cancellation_fee);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_id: String)]
+pub struct EarlyTerminateRentalToken<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = payer,
+        token::token_program = token_program,
+    )]
+    pub payer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [b"vault", &[VaultKind::RentalTreasury as u8]],
+        bump = vault_account.bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault_account,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"token_ledger", mint.key().as_ref()],
+        bump = token_ledger.bump,
+    )]
+    pub token_ledger: Account<'info, TokenLedger>,
+
+    #[account(
+        mut,
+        seeds = [b"rental_session", payer.key().as_ref(), _id.as_bytes()],
+        bump = rental_session.bump,
+    )]
+    pub rental_session: Account<'info, RentalSession>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Rentals) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
@@ -1,16 +1,27 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::Errors, state::RentalSession};
+use crate::{errors::Errors, state::{OperatorRole, PauseCategory, ProgramConfig, RentalSession, RentalStatus, Role, TokenLedger, VaultAccount, VaultKind}};
 
 pub fn end_rental_session(ctx: Context<EndRentalSession>, _id: String, _user_pub_key: Pubkey) -> Result<()> {
     let rental_session = &mut ctx.accounts.rental_session;
-    require!(rental_session.is_active, Errors::RentalExpired);
     require!(rental_session.id == _id, Errors::Unauthorized);
 
-    rental_session.is_active = false;
+    // Escrow-backed sessions must settle through finalize so the escrow status moves with the rental.
+    require!(rental_session.duration_seconds > 0, Errors::InvalidDuration);
+
+    match rental_session.mint {
+        None => ctx.accounts.vault_account.release_liability(rental_session.amount_paid),
+        Some(mint) => {
+            let token_ledger = ctx.accounts.token_ledger.as_mut()
+                .filter(|ledger| ledger.mint == mint)
+                .ok_or(Errors::PaymentMintMismatch)?;
+            token_ledger.release_liability(rental_session.amount_paid);
+        }
+    }
+    rental_session.status.transition(RentalStatus::Finalized)?;
     rental_session.amount_paid = 0;
 
-    msg!("Rental session ended for user: {}", ctx.accounts.payer.key());
+    msg!("Rental session ended for user: {}", rental_session.user);
     Ok(())
 }
 
@@ -18,13 +29,38 @@ pub fn end_rental_session(ctx: Context<EndRentalSession>, _id: String, _user_pub
 #[instruction(_id: String, _user_pub_key: Pubkey)]
 pub struct EndRentalSession<'info> {
     #[account(mut)]
-    pub payer: Signer<'info>,
+    pub operator: Signer<'info>,
+
+    #[account(
+        seeds = [b"operator_role", operator.key().as_ref(), &[Role::SessionOperator as u8]],
+        bump = operator_role.bump,
+    )]
+    pub operator_role: Account<'info, OperatorRole>,
+
     #[account(
         mut,
         seeds = [b"rental_session", _user_pub_key.as_ref(), _id.as_bytes()],
         bump = rental_session.bump,
-        constraint = rental_session.is_active @ Errors::RentalExpired,
+        constraint = rental_session.status == RentalStatus::Active @ Errors::RentalExpired,
         constraint = rental_session.user == _user_pub_key @ Errors::Unauthorized,
     )]
     pub rental_session: Account<'info, RentalSession>,
+    #[account(
+        mut,
+        seeds = [b"vault", &[VaultKind::RentalTreasury as u8]],
+        bump = vault_account.bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    #[account(
+        mut,
+        seeds = [b"token_ledger", token_ledger.mint.as_ref()],
+        bump = token_ledger.bump,
+    )]
+    pub token_ledger: Option<Account<'info, TokenLedger>>,
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Rentals) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
 }
\ No newline at end of file
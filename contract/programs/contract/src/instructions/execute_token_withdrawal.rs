@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+
+use crate::{errors::Errors, state::{PauseCategory, ProgramConfig, ProposalStatus, TokenLedger, TreasuryPolicy, VaultAccount, VaultKind, WithdrawalProposal}};
+
+// Token withdrawals always go through a proposal: the direct withdrawal limit is denominated in lamports.
+pub fn execute_token_withdrawal(ctx: Context<ExecuteTokenWithdrawal>, index: u64) -> Result<()> {
+    let policy = &ctx.accounts.treasury_policy;
+    let proposal = &mut ctx.accounts.proposal;
+
+    require!(proposal.status == ProposalStatus::Pending, Errors::ProposalNotPending);
+    require!(
+        proposal.approval_count(policy) >= policy.threshold as usize,
+        Errors::ThresholdNotMet
+    );
+    require!(
+        Clock::get()?.unix_timestamp >= proposal.executable_at,
+        Errors::TimelockNotElapsed
+    );
+
+    let token_ledger = &mut ctx.accounts.token_ledger;
+    token_ledger.mint = ctx.accounts.mint.key();
+    token_ledger.bump = ctx.bumps.token_ledger;
+
+    // Prepaid token rentals sit in the treasury's ATA until they end
+    let amount = proposal.amount;
+    let held = ctx.accounts.vault_token_account.amount;
+    let free_balance = if proposal.vault_kind == VaultKind::RentalTreasury {
+        token_ledger.free_balance(held)
+    } else {
+        held
+    };
+    require!(free_balance >= amount, Errors::ExceedsFreeBalance);
+
+    let vault_seeds = &[
+        b"vault".as_ref(),
+        &[proposal.vault_kind as u8],
+        &[ctx.accounts.vault_account.bump],
+    ];
+    let signer_seeds = &[&vault_seeds[..]];
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token_interface::TransferChecked {
+            from: ctx.accounts.vault_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.destination_token_account.to_account_info(),
+            authority: ctx.accounts.vault_account.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::transfer_checked(cpi_context, amount, ctx.accounts.mint.decimals)?;
+
+    proposal.status = ProposalStatus::Executed;
+
+    msg!("Token withdrawal {} executed: {} of {} to {}", index, amount, ctx.accounts.mint.key(), proposal.destination);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(index: u64)]
+pub struct ExecuteTokenWithdrawal<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury_policy"],
+        bump = treasury_policy.bump,
+    )]
+    pub treasury_policy: Box<Account<'info, TreasuryPolicy>>,
+
+    #[account(
+        mut,
+        seeds = [b"withdrawal_proposal", &index.to_le_bytes()],
+        bump = proposal.bump,
+        constraint = proposal.mint == Some(mint.key()) @ Errors::PaymentMintMismatch,
+    )]
+    pub proposal: Box<Account<'info, WithdrawalProposal>>,
+
+    #[account(
+        seeds = [b"vault", &[proposal.vault_kind as u8]],
+        bump = vault_account.bump,
+    )]
+    pub vault_account: Box<Account<'info, VaultAccount>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault_account,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = 8 + TokenLedger::SIZE,
+        seeds = [b"token_ledger", mint.key().as_ref()],
+        bump
+    )]
+    pub token_ledger: Box<Account<'info, TokenLedger>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = proposal.destination,
+        token::token_program = token_program,
+    )]
+    pub destination_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Vault) @ Errors::ProgramPaused,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
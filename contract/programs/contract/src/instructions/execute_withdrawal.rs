@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::Errors, state::{PauseCategory, ProgramConfig, ProposalStatus, TreasuryPolicy, VaultAccount, WithdrawalProposal}};
+
+pub fn execute_withdrawal(ctx: Context<ExecuteWithdrawal>, index: u64) -> Result<()> {
+    let policy = &ctx.accounts.treasury_policy;
+    let proposal = &mut ctx.accounts.proposal;
+
+    require!(proposal.status == ProposalStatus::Pending, Errors::ProposalNotPending);
+    require!(proposal.mint.is_none(), Errors::PaymentMintMismatch);
+    require!(
+        proposal.approval_count(policy) >= policy.threshold as usize,
+        Errors::ThresholdNotMet
+    );
+    require!(
+        Clock::get()?.unix_timestamp >= proposal.executable_at,
+        Errors::TimelockNotElapsed
+    );
+
+    let amount = proposal.amount;
+    let vault_balance = ctx.accounts.vault_account.to_account_info().lamports();
+    require!(
+        ctx.accounts.vault_account.free_balance(vault_balance)? >= amount,
+        Errors::ExceedsFreeBalance
+    );
+
+    **ctx.accounts.vault_account.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.destination.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    proposal.status = ProposalStatus::Executed;
+
+    msg!("Withdrawal {} executed: {} lamports to {}", index, amount, proposal.destination);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(index: u64)]
+pub struct ExecuteWithdrawal<'info> {
+    pub executor: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury_policy"],
+        bump = treasury_policy.bump,
+    )]
+    pub treasury_policy: Account<'info, TreasuryPolicy>,
+
+    #[account(
+        mut,
+        seeds = [b"withdrawal_proposal", &index.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, WithdrawalProposal>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", &[proposal.vault_kind as u8]],
+        bump = vault_account.bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+
+    /// CHECK: Destination recorded on the proposal
+    #[account(
+        mut,
+        constraint = destination.key() == proposal.destination @ Errors::Unauthorized,
+    )]
+    pub destination: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Vault) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::Errors, events::RentalExpired, state::{PauseCategory, ProgramConfig, RentalSession, RentalStatus, TokenLedger, VaultAccount, VaultKind}};
+
+pub fn expire_rental(ctx: Context<ExpireRental>, _id: String, _user_pub_key: Pubkey) -> Result<()> {
+    let rental_session = &mut ctx.accounts.rental_session;
+    require!(rental_session.status == RentalStatus::Active, Errors::NotActive);
+    // Escrow-backed sessions have no fixed end and are settled through finalize instead
+    require!(rental_session.duration_seconds > 0, Errors::InvalidDuration);
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(current_time >= rental_session.end_time, Errors::RentalNotExpired);
+
+    // The bounty comes out of the rental's own payment so the crank can't drain the treasury.
+    // Token rentals never touch the lamport treasury, so they only release their token liability and pay no bounty.
+    let mut bounty = 0;
+    match rental_session.mint {
+        None => {
+            bounty = ctx.accounts.config.expiry_bounty.min(rental_session.amount_paid);
+            ctx.accounts.vault_account.release_liability(rental_session.amount_paid);
+            **ctx.accounts.vault_account.to_account_info().try_borrow_mut_lamports()? -= bounty;
+            **ctx.accounts.caller.to_account_info().try_borrow_mut_lamports()? += bounty;
+        }
+        Some(mint) => {
+            let token_ledger = ctx.accounts.token_ledger.as_mut()
+                .filter(|ledger| ledger.mint == mint)
+                .ok_or(Errors::PaymentMintMismatch)?;
+            token_ledger.release_liability(rental_session.amount_paid);
+        }
+    }
+
+    rental_session.consumed_seconds = rental_session.duration_seconds;
+    rental_session.status.transition(RentalStatus::Expired)?;
+    rental_session.amount_paid = 0;
+
+    emit!(RentalExpired {
+        user: rental_session.user,
+        id: rental_session.id.clone(),
+        end_time: rental_session.end_time,
+        caller: ctx.accounts.caller.key(),
+        bounty,
+    });
+
+    msg!("Rental {} expired, bounty of {} lamports paid to {}", rental_session.id, bounty, ctx.accounts.caller.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_id: String, _user_pub_key: Pubkey)]
+pub struct ExpireRental<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"rental_session", _user_pub_key.as_ref(), _id.as_bytes()],
+        bump = rental_session.bump,
+        constraint = rental_session.user == _user_pub_key @ Errors::Unauthorized,
+    )]
+    pub rental_session: Account<'info, RentalSession>,
+    #[account(
+        mut,
+        seeds = [b"vault", &[VaultKind::RentalTreasury as u8]],
+        bump = vault_account.bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    #[account(
+        mut,
+        seeds = [b"token_ledger", token_ledger.mint.as_ref()],
+        bump = token_ledger.bump,
+    )]
+    pub token_ledger: Option<Account<'info, TokenLedger>>,
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Rentals) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::Errors;
+use crate::state::{PauseCategory, ProgramConfig, RentalSession, RentalStatus};
+use crate::state::{VaultAccount, VaultKind};
+
+pub fn extend_rental(
+    ctx: Context<ExtendRental>,
+    _id: String,
+    amount: u64,
+    additional_seconds: i64,
+) -> Result<()> {
+    require!(amount > 0, Errors::InvalidAmount);
+    require!(additional_seconds > 0, Errors::InvalidDuration);
+
+    let rental_session = &ctx.accounts.rental_session;
+    require!(rental_session.status == RentalStatus::Active, Errors::NotActive);
+    require!(rental_session.user == ctx.accounts.payer.key(), Errors::Unauthorized);
+    require!(rental_session.duration_seconds > 0, Errors::InvalidDuration);
+    require!(rental_session.mint.is_none(), Errors::PaymentMintMismatch);
+    require!(Clock::get()?.unix_timestamp < rental_session.end_time, Errors::RentalExpired);
+
+    let cpi_context = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+            from: ctx.accounts.payer.to_account_info(),
+            to: ctx.accounts.vault_account.to_account_info(),
+        },
+    );
+    system_program::transfer(cpi_context, amount)?;
+    ctx.accounts.vault_account.add_liability(amount)?;
+
+    let rental_session = &mut ctx.accounts.rental_session;
+    rental_session.extend(amount, additional_seconds)?;
+
+    msg!("Extended rental {} by {}s, new end time: {}", rental_session.id, additional_seconds, rental_session.end_time);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_id: String)]
+pub struct ExtendRental<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", &[VaultKind::RentalTreasury as u8]],
+        bump = vault_account.bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"rental_session", payer.key().as_ref(), _id.as_bytes()],
+        bump = rental_session.bump,
+    )]
+    pub rental_session: Account<'info, RentalSession>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Rentals) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+
+use crate::errors::Errors;
+use crate::state::{PauseCategory, ProgramConfig, RentalSession, RentalStatus, TokenLedger};
+use crate::state::{VaultAccount, VaultKind};
+
+pub fn extend_rental_token(
+    ctx: Context<ExtendRentalToken>,
+    _id: String,
+    amount: u64,
+    additional_seconds: i64,
+) -> Result<()> {
+    require!(amount > 0, Errors::InvalidAmount);
+    require!(additional_seconds > 0, Errors::InvalidDuration);
+
+    let rental_session = &ctx.accounts.rental_session;
+    require!(rental_session.status == RentalStatus::Active, Errors::NotActive);
+    require!(rental_session.user == ctx.accounts.payer.key(), Errors::Unauthorized);
+    require!(rental_session.duration_seconds > 0, Errors::InvalidDuration);
+    require!(rental_session.mint == Some(ctx.accounts.mint.key()), Errors::PaymentMintMismatch);
+    require!(Clock::get()?.unix_timestamp < rental_session.end_time, Errors::RentalExpired);
+
+    // Transfer-fee mints deliver less than was sent, so the rental is credited with what actually arrived
+    let balance_before = ctx.accounts.treasury_token_account.amount;
+    let cpi_context = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        token_interface::TransferChecked {
+            from: ctx.accounts.payer_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.treasury_token_account.to_account_info(),
+            authority: ctx.accounts.payer.to_account_info(),
+        },
+    );
+    token_interface::transfer_checked(cpi_context, amount, ctx.accounts.mint.decimals)?;
+    ctx.accounts.treasury_token_account.reload()?;
+    let received = ctx.accounts.treasury_token_account.amount - balance_before;
+    require!(received > 0, Errors::InvalidAmount);
+    ctx.accounts.token_ledger.add_liability(received)?;
+
+    let rental_session = &mut ctx.accounts.rental_session;
+    rental_session.extend(received, additional_seconds)?;
+
+    msg!("Extended token rental {} by {}s, new end time: {}", rental_session.id, additional_seconds, rental_session.end_time);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_id: String)]
+pub struct ExtendRentalToken<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = payer,
+        token::token_program = token_program,
+    )]
+    pub payer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [b"vault", &[VaultKind::RentalTreasury as u8]],
+        bump = vault_account.bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault_account,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"token_ledger", mint.key().as_ref()],
+        bump = token_ledger.bump,
+    )]
+    pub token_ledger: Account<'info, TokenLedger>,
+
+    #[account(
+        mut,
+        seeds = [b"rental_session", payer.key().as_ref(), _id.as_bytes()],
+        bump = rental_session.bump,
+    )]
+    pub rental_session: Account<'info, RentalSession>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Rentals) @ Errors::ProgramPaused,
+        constraint = config.is_mint_allowed(&mint.key()) @ Errors::MintNotAllowed,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
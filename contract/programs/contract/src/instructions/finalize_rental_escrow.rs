@@ -1,18 +1,30 @@
 use anchor_lang::{prelude::*, system_program};
 
-use crate::{errors::Errors, state::{EscrowSession, RentalSession, VaultAccount}};
+use crate::{errors::Errors, events::RentalSettled, state::{EscrowSession, HostMachineRegistration, ProgramConfig, RentalSession, RentalStatus, VaultAccount, VaultKind}};
 
-pub fn finalize_rental_escrow(ctx: Context<FinalizeRentalEscrow>, _id: String, amount: u64, _secret_key: String) -> Result<()> {
+// Deliberately not gated by the pause flags so users can always exit an active escrow.
+pub fn finalize_rental_escrow(ctx: Context<FinalizeRentalEscrow>, _id: String) -> Result<()> {
     let rental = &mut ctx.accounts.rental_session;
     let escrow_session = &mut ctx.accounts.escrow_session;
     let escrow_vault = &ctx.accounts.escrow_vault;
     let user = &mut ctx.accounts.user;
+    let now = Clock::get()?.unix_timestamp;
 
-    require!(rental.is_active, Errors::NotActive);
-    require!(escrow_session.is_active, Errors::EscrowNotActive);
+    require!(rental.status == RentalStatus::Active, Errors::NotActive);
+    require!(escrow_session.status == RentalStatus::Active, Errors::EscrowNotActive);
     require!(escrow_session.user == user.key(), Errors::EscrowNotFound);
     require!(escrow_session.id == _id, Errors::EscrowNotFound);
-    require!(escrow_vault.lamports() >= amount, Errors::InsufficientFunds);
+    require!(escrow_session.mint.is_none(), Errors::PaymentMintMismatch);
+    // Once the escrow is used up anyone, typically the host, can settle it and free the machine
+    require!(
+        ctx.accounts.caller.key() == user.key() || escrow_session.is_exhausted(now),
+        Errors::Unauthorized
+    );
+
+    // Bill the elapsed time at the rate fixed when the rental started and refund the unused escrow
+    let charge = escrow_session.metered_charge(now);
+    let refund = escrow_session.amount - charge;
+    require!(escrow_vault.lamports() >= refund, Errors::InsufficientFunds);
 
     let (escrow_vault_key, escrow_vault_bump) = Pubkey::find_program_address(
         &[b"escrow_vault", user.key().as_ref(), ctx.accounts.admin.key().as_ref(), _id.as_bytes()],
@@ -31,7 +43,7 @@ pub fn finalize_rental_escrow(ctx: Context<FinalizeRentalEscrow>, _id: String, a
     ];
     let signer_seeds = &[&escrow_vault_seeds[..]];
     
-    // Transfer the requested amount to the user
+    // Refund the unused escrow to the user
     let cpi_context = CpiContext::new_with_signer(
         ctx.accounts.system_program.to_account_info(),
         system_program::Transfer {
@@ -40,50 +52,120 @@ pub fn finalize_rental_escrow(ctx: Context<FinalizeRentalEscrow>, _id: String, a
         },
         signer_seeds,
     );
-    system_program::transfer(cpi_context, amount)?;
+    system_program::transfer(cpi_context, refund)?;
 
-    // Transfer the remaining amount to vault (if any)
-    let remaining_amount = escrow_vault.to_account_info().lamports();
-    
-    if remaining_amount > 0 {
-        let cpi_context_vault = CpiContext::new_with_signer(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.escrow_vault.to_account_info(),
-                to: ctx.accounts.vault_account.to_account_info(),
-            },
+    // Split the charge between the protocol fee, the referrer and the host payout
+    let referrer = escrow_session.referrer;
+    let split = ctx.accounts.config.settlement_split(charge, referrer.is_some())?;
+
+    transfer_from_escrow(
+        &ctx.accounts.system_program,
+        escrow_vault,
+        &ctx.accounts.fee_vault.to_account_info(),
+        signer_seeds,
+        split.protocol_fee,
+    )?;
+
+    if let Some(referrer_key) = referrer {
+        let referrer_account = ctx.accounts.referrer.as_ref().ok_or(Errors::ReferrerMismatch)?;
+        require!(referrer_account.key() == referrer_key, Errors::ReferrerMismatch);
+        transfer_from_escrow(
+            &ctx.accounts.system_program,
+            escrow_vault,
+            &referrer_account.to_account_info(),
             signer_seeds,
-        );
-        system_program::transfer(cpi_context_vault, remaining_amount)?;
+            split.referrer_fee,
+        )?;
     }
 
-    // Update escrow session
-    escrow_session.is_active = false;
-    escrow_session.amount = 0;
-    escrow_session.user = Pubkey::default();
-    escrow_session.id = String::new();
-    escrow_session.bump = 0; 
-
-    // Update rental session
-    rental.is_active = false;
-    rental.end_time = Clock::get()?.unix_timestamp;
-    rental.amount_paid = 0;
-    
-    msg!("Rental finalized successfully. Amount transferred: {}", amount);
+    // Host rentals are credited to the host's earnings in the rewards pool, the rest stays with the treasury
+    let host_machine_key = rental.host_machine;
+    if let Some(host_machine_key) = host_machine_key {
+        let host_machine = ctx.accounts.host_machine.as_mut().ok_or(Errors::HostMachineMismatch)?;
+        require!(host_machine.key() == host_machine_key, Errors::HostMachineMismatch);
+        transfer_from_escrow(
+            &ctx.accounts.system_program,
+            escrow_vault,
+            &ctx.accounts.rewards_vault.to_account_info(),
+            signer_seeds,
+            split.host_payout,
+        )?;
+        host_machine.earned = host_machine.earned.checked_add(split.host_payout)
+            .ok_or(Errors::ArithmeticOverflow)?;
+        host_machine.occupied = false;
+        ctx.accounts.rewards_vault.add_liability(split.host_payout)?;
+    } else {
+        transfer_from_escrow(
+            &ctx.accounts.system_program,
+            escrow_vault,
+            &ctx.accounts.vault_account.to_account_info(),
+            signer_seeds,
+            split.host_payout,
+        )?;
+    }
+
+    emit!(RentalSettled {
+        user: user_key,
+        id: _id.clone(),
+        refunded: refund,
+        host_payout: split.host_payout,
+        protocol_fee: split.protocol_fee,
+        referrer_fee: split.referrer_fee,
+        referrer,
+        host_machine: host_machine_key,
+    });
+
+    rental.status.transition(RentalStatus::Finalized)?;
+    escrow_session.status.transition(RentalStatus::Finalized)?;
+
+    // Hand the escrow vault's rent back to the user; the session accounts are closed on exit
+    transfer_from_escrow(
+        &ctx.accounts.system_program,
+        escrow_vault,
+        &ctx.accounts.user.to_account_info(),
+        signer_seeds,
+        escrow_vault.lamports(),
+    )?;
+
+    msg!("Rental finalized successfully. Charged: {}, refunded: {}", charge, refund);
     Ok(())
 }
 
+fn transfer_from_escrow<'info>(
+    system_program: &Program<'info, System>,
+    escrow_vault: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let cpi_context = CpiContext::new_with_signer(
+        system_program.to_account_info(),
+        system_program::Transfer {
+            from: escrow_vault.clone(),
+            to: to.clone(),
+        },
+        signer_seeds,
+    );
+    system_program::transfer(cpi_context, amount)
+}
+
 #[derive(Accounts)]
-#[instruction(_id: String, amount: u64, _secret_key: String)]
+#[instruction(_id: String)]
 pub struct FinalizeRentalEscrow<'info> {
+    pub caller: Signer<'info>,
+
     #[account(mut)]
-    pub user: Signer<'info>,
+    pub user: SystemAccount<'info>,
 
-    ///CHECK: Admin account for vault transfers
+    ///CHECK: Admin account the escrow vault was opened against
     pub admin: UncheckedAccount<'info>,
 
     #[account(
         mut,
+        close = user,
         seeds = [b"rental_session", user.key().as_ref(), _id.as_bytes()],
         bump,
     )]
@@ -91,6 +173,7 @@ pub struct FinalizeRentalEscrow<'info> {
 
     #[account(
         mut,
+        close = user,
         seeds = [b"escrow_session", user.key().as_ref(), _id.as_bytes()],
         bump,
     )]
@@ -106,10 +189,36 @@ pub struct FinalizeRentalEscrow<'info> {
 
     #[account(
         mut,
-        seeds = [b"vault_account", admin.key().as_ref(), _secret_key.as_bytes()],
+        seeds = [b"vault", &[VaultKind::RentalTreasury as u8]],
         bump = vault_account.bump,
-        constraint = vault_account.owner == admin.key() @ Errors::Unauthorized,
     )]
     pub vault_account: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", &[VaultKind::FeePool as u8]],
+        bump = fee_vault.bump,
+    )]
+    pub fee_vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", &[VaultKind::RewardsPool as u8]],
+        bump = rewards_vault.bump,
+    )]
+    pub rewards_vault: Account<'info, VaultAccount>,
+
+    #[account(mut)]
+    pub host_machine: Option<Account<'info, HostMachineRegistration>>,
+
+    /// CHECK: Referrer recorded on the escrow session, verified in the handler
+    #[account(mut)]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
@@ -0,0 +1,277 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::memo::{self, Memo};
+use anchor_spl::token_2022::spl_token_2022::{self, extension::{BaseStateWithExtensions, StateWithExtensions}};
+use anchor_spl::token_2022::spl_token_2022::extension::{memo_transfer::memo_required, transfer_fee::TransferFeeAmount};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+
+use crate::{errors::Errors, events::RentalSettled, state::{EscrowSession, HostMachineRegistration, ProgramConfig, RentalSession, RentalStatus, VaultAccount, VaultKind}};
+
+// Deliberately not gated by the pause flags so users can always exit an active escrow.
+pub fn finalize_rental_escrow_token(ctx: Context<FinalizeRentalEscrowToken>, _id: String) -> Result<()> {
+    let escrow_session = &ctx.accounts.escrow_session;
+    let user_key = ctx.accounts.user.key();
+    let mint_key = ctx.accounts.mint.key();
+
+    require!(ctx.accounts.rental_session.status == RentalStatus::Active, Errors::NotActive);
+    require!(escrow_session.status == RentalStatus::Active, Errors::EscrowNotActive);
+    require!(escrow_session.user == user_key, Errors::EscrowNotFound);
+    require!(escrow_session.id == _id, Errors::EscrowNotFound);
+    require!(escrow_session.mint == Some(mint_key), Errors::PaymentMintMismatch);
+    // Once the escrow is used up anyone, typically the host, can settle it and free the machine
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.caller.key() == user_key || escrow_session.is_exhausted(now),
+        Errors::Unauthorized
+    );
+
+    // Bill the elapsed time at the rate fixed when the rental started and refund the unused escrow
+    let charge = escrow_session.metered_charge(now);
+    let refund = escrow_session.amount - charge;
+    let referrer = escrow_session.referrer;
+    let split = ctx.accounts.config.settlement_split(charge, referrer.is_some())?;
+
+    let escrow_session_seeds = &[
+        b"escrow_session",
+        user_key.as_ref(),
+        _id.as_bytes(),
+        &[escrow_session.bump],
+    ];
+    let signer_seeds = &[&escrow_session_seeds[..]];
+    let escrow = EscrowSigner {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        memo_program: ctx.accounts.memo_program.to_account_info(),
+        escrow_token_account: ctx.accounts.escrow_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        decimals: ctx.accounts.mint.decimals,
+        authority: ctx.accounts.escrow_session.to_account_info(),
+    };
+
+    escrow.transfer(&ctx.accounts.user_token_account.to_account_info(), signer_seeds, refund)?;
+    escrow.transfer(&ctx.accounts.fee_token_account.to_account_info(), signer_seeds, split.protocol_fee)?;
+
+    if let Some(referrer_key) = referrer {
+        let referrer_token_account = ctx.accounts.referrer_token_account.as_ref().ok_or(Errors::ReferrerMismatch)?;
+        require!(
+            referrer_token_account.owner == referrer_key && referrer_token_account.mint == mint_key,
+            Errors::ReferrerMismatch
+        );
+        escrow.transfer(&referrer_token_account.to_account_info(), signer_seeds, split.referrer_fee)?;
+    }
+
+    // Host earnings are tracked in lamports, so token rentals pay the host's token account directly
+    let host_machine_key = ctx.accounts.rental_session.host_machine;
+    if let Some(host_machine_key) = host_machine_key {
+        let host_machine = ctx.accounts.host_machine.as_mut().ok_or(Errors::HostMachineMismatch)?;
+        require!(host_machine.key() == host_machine_key, Errors::HostMachineMismatch);
+        let host_token_account = ctx.accounts.host_token_account.as_ref().ok_or(Errors::HostMachineMismatch)?;
+        require!(
+            host_token_account.owner == host_machine.payout_wallet && host_token_account.mint == mint_key,
+            Errors::HostMachineMismatch
+        );
+        escrow.transfer(&host_token_account.to_account_info(), signer_seeds, split.host_payout)?;
+        host_machine.occupied = false;
+    } else {
+        escrow.transfer(&ctx.accounts.treasury_token_account.to_account_info(), signer_seeds, split.host_payout)?;
+    }
+
+    // Return anything sent to the escrow outside top-ups, then close it so its rent goes back to the user
+    ctx.accounts.escrow_token_account.reload()?;
+    let leftover = ctx.accounts.escrow_token_account.amount;
+    escrow.transfer(&ctx.accounts.user_token_account.to_account_info(), signer_seeds, leftover)?;
+    escrow.close(&ctx.accounts.user.to_account_info(), signer_seeds)?;
+
+    emit!(RentalSettled {
+        user: user_key,
+        id: _id.clone(),
+        refunded: refund,
+        host_payout: split.host_payout,
+        protocol_fee: split.protocol_fee,
+        referrer_fee: split.referrer_fee,
+        referrer,
+        host_machine: host_machine_key,
+    });
+
+    // The session accounts are closed to the user on exit
+    ctx.accounts.rental_session.status.transition(RentalStatus::Finalized)?;
+    ctx.accounts.escrow_session.status.transition(RentalStatus::Finalized)?;
+
+    msg!("Token rental finalized successfully. Charged: {}, refunded: {}", charge, refund);
+    Ok(())
+}
+
+/// Moves tokens out of the escrow on behalf of the escrow session PDA, covering the Token-2022
+/// extensions a payment mint or a recipient account may carry.
+struct EscrowSigner<'info> {
+    token_program: AccountInfo<'info>,
+    memo_program: AccountInfo<'info>,
+    escrow_token_account: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    decimals: u8,
+    authority: AccountInfo<'info>,
+}
+
+impl<'info> EscrowSigner<'info> {
+    fn transfer(&self, to: &AccountInfo<'info>, signer_seeds: &[&[&[u8]]], amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        // Memo-required accounts reject transfers that aren't directly preceded by a memo
+        if requires_memo(to)? {
+            memo::build_memo(CpiContext::new(self.memo_program.clone(), memo::BuildMemo {}), b"rental settlement")?;
+        }
+        let cpi_context = CpiContext::new_with_signer(
+            self.token_program.clone(),
+            token_interface::TransferChecked {
+                from: self.escrow_token_account.clone(),
+                mint: self.mint.clone(),
+                to: to.clone(),
+                authority: self.authority.clone(),
+            },
+            signer_seeds,
+        );
+        token_interface::transfer_checked(cpi_context, amount, self.decimals)
+    }
+
+    fn close(&self, destination: &AccountInfo<'info>, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        // Fees withheld on incoming transfers block closing until they are harvested to the mint
+        if withheld_fees(&self.escrow_token_account)? > 0 {
+            token_interface::harvest_withheld_tokens_to_mint(
+                CpiContext::new(
+                    self.token_program.clone(),
+                    token_interface::HarvestWithheldTokensToMint {
+                        token_program_id: self.token_program.clone(),
+                        mint: self.mint.clone(),
+                    },
+                ),
+                vec![self.escrow_token_account.clone()],
+            )?;
+        }
+        token_interface::close_account(CpiContext::new_with_signer(
+            self.token_program.clone(),
+            token_interface::CloseAccount {
+                account: self.escrow_token_account.clone(),
+                destination: destination.clone(),
+                authority: self.authority.clone(),
+            },
+            signer_seeds,
+        ))
+    }
+}
+
+fn requires_memo(account: &AccountInfo) -> Result<bool> {
+    if *account.owner != spl_token_2022::ID {
+        return Ok(false);
+    }
+    let data = account.try_borrow_data()?;
+    let state = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+    Ok(memo_required(&state))
+}
+
+fn withheld_fees(account: &AccountInfo) -> Result<u64> {
+    if *account.owner != spl_token_2022::ID {
+        return Ok(0);
+    }
+    let data = account.try_borrow_data()?;
+    let state = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+    Ok(state
+        .get_extension::<TransferFeeAmount>()
+        .map(|fees| u64::from(fees.withheld_amount))
+        .unwrap_or(0))
+}
+
+#[derive(Accounts)]
+#[instruction(_id: String)]
+pub struct FinalizeRentalEscrowToken<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(mut)]
+    pub user: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"rental_session", user.key().as_ref(), _id.as_bytes()],
+        bump = rental_session.bump,
+    )]
+    pub rental_session: Box<Account<'info, RentalSession>>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"escrow_session", user.key().as_ref(), _id.as_bytes()],
+        bump = escrow_session.bump,
+    )]
+    pub escrow_session: Box<Account<'info, EscrowSession>>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow_token", user.key().as_ref(), _id.as_bytes()],
+        bump,
+        token::mint = mint,
+        token::authority = escrow_session,
+        token::token_program = token_program,
+    )]
+    pub escrow_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = user,
+        token::token_program = token_program,
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [b"vault", &[VaultKind::RentalTreasury as u8]],
+        bump = vault_account.bump,
+    )]
+    pub vault_account: Box<Account<'info, VaultAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = mint,
+        associated_token::authority = vault_account,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [b"vault", &[VaultKind::FeePool as u8]],
+        bump = fee_vault.bump,
+    )]
+    pub fee_vault: Box<Account<'info, VaultAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = mint,
+        associated_token::authority = fee_vault,
+        associated_token::token_program = token_program,
+    )]
+    pub fee_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub host_machine: Option<Box<Account<'info, HostMachineRegistration>>>,
+
+    #[account(mut)]
+    pub host_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub referrer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub memo_program: Program<'info, Memo>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
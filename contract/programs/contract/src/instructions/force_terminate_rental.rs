@@ -1,22 +1,23 @@
 use anchor_lang::{prelude::*, system_program};
 
-use crate::{errors::Errors, state::{EscrowSession, RentalSession, VaultAccount}};
+use crate::{errors::Errors, state::{EscrowSession, HostMachineRegistration, OperatorRole, PauseCategory, ProgramConfig, RentalSession, RentalStatus, Role, VaultAccount, VaultKind}};
 
 pub fn force_terminate_rental(
     ctx: Context<ForceTerminateRental>,
     id: String,
-    _secret_key: String,
 ) -> Result<()> {
     let rental = &mut ctx.accounts.rental_session;
     let escrow_session = &mut ctx.accounts.escrow_session;
     let escrow_vault = &ctx.accounts.escrow_vault;
     let user = &ctx.accounts.user;
 
-    require!(rental.is_active, Errors::NotActive);
-    require!(escrow_session.is_active, Errors::EscrowNotActive);
+    require!(rental.status == RentalStatus::Active, Errors::NotActive);
+    require!(escrow_session.status == RentalStatus::Active, Errors::EscrowNotActive);
     require!(rental.id == id, Errors::Unauthorized);
     require!(escrow_session.id == id, Errors::Unauthorized);
+    require!(escrow_session.mint.is_none(), Errors::PaymentMintMismatch);
     require!(ctx.accounts.user.key() == rental.user, Errors::Unauthorized);
+    require!(ctx.accounts.admin.key() == escrow_session.vault_admin, Errors::Unauthorized);
 
     let (escrow_vault_key, escrow_vault_bump) = Pubkey::find_program_address(
         &[b"escrow_vault", user.key().as_ref(), ctx.accounts.admin.key().as_ref(), id.as_bytes()],
@@ -44,34 +45,62 @@ pub fn force_terminate_rental(
         },
         signer_seeds,
     );
-    system_program::transfer(cpi_context_vault, escrow_vault.lamports())?;
-
-    // Deactivate the rental and escrow sessions
-    rental.is_active = false;
-    rental.end_time = Clock::get()?.unix_timestamp;
-    rental.amount_paid = 0;
-
-    escrow_session.is_active = false;
-    escrow_session.user = Pubkey::default();
-    escrow_session.id = String::new();
-    escrow_session.bump = 0; 
-    escrow_session.amount = 0;
-
+    system_program::transfer(cpi_context_vault, escrow_session.amount)?;
 
+    // The escrowed funds are forfeited, but the vault's rent goes back to the user who paid it
+    let cpi_context_user = CpiContext::new_with_signer(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+            from: ctx.accounts.escrow_vault.to_account_info(),
+            to: ctx.accounts.user.to_account_info(),
+        },
+        signer_seeds,
+    );
+    system_program::transfer(cpi_context_user, escrow_vault.lamports())?;
+
+    // Free up the host the rental was running on
+    if let Some(host_machine_key) = rental.host_machine {
+        let host_machine = ctx.accounts.host_machine.as_mut().ok_or(Errors::HostMachineMismatch)?;
+        require!(host_machine.key() == host_machine_key, Errors::HostMachineMismatch);
+        host_machine.occupied = false;
+    }
+
+    // The rental and escrow sessions are closed to the user on exit
+    rental.status.transition(RentalStatus::Terminated)?;
+    escrow_session.status.transition(RentalStatus::Terminated)?;
+    msg!("Rental {} force terminated, {} lamports forfeited", id, escrow_session.amount);
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(id: String, _secret_key: String)]
+#[instruction(id: String)]
 pub struct ForceTerminateRental<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub operator: Signer<'info>,
 
-    /// CHECK: User account to be terminated
+    #[account(
+        seeds = [b"operator_role", operator.key().as_ref(), &[Role::SessionOperator as u8]],
+        bump = operator_role.bump,
+    )]
+    pub operator_role: Account<'info, OperatorRole>,
+
+    /// CHECK: Admin account the escrow vault was opened against
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Escrow) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// CHECK: User account to be terminated, receives the reclaimed rent
+    #[account(mut)]
     pub user: UncheckedAccount<'info>,
 
     #[account(
         mut,
+        close = user,
         seeds = [b"rental_session", user.key().as_ref(), id.as_bytes()],
         bump
     )]
@@ -79,6 +108,7 @@ pub struct ForceTerminateRental<'info> {
 
     #[account(
         mut,
+        close = user,
         seeds = [b"escrow_session", user.key().as_ref(), id.as_bytes()],
         bump
     )]
@@ -92,11 +122,13 @@ pub struct ForceTerminateRental<'info> {
     )]
     pub escrow_vault: UncheckedAccount<'info>,
 
+    #[account(mut)]
+    pub host_machine: Option<Account<'info, HostMachineRegistration>>,
+
     #[account(
         mut,
-        seeds = [b"vault_account", admin.key().as_ref(), _secret_key.as_bytes()],
+        seeds = [b"vault", &[VaultKind::RentalTreasury as u8]],
         bump = vault_account.bump,
-        constraint = vault_account.owner == admin.key() @ Errors::Unauthorized,
     )]
     pub vault_account: Account<'info, VaultAccount>,
 
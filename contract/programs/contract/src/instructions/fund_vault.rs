@@ -1,13 +1,13 @@
 use anchor_lang::{prelude::*, system_program};
-use crate::{errors::Errors, state::{ VaultAccount}};
+use crate::{errors::Errors, state::{PauseCategory, ProgramConfig, VaultAccount, VaultKind}};
 
-pub fn fund_vault(ctx: Context<FundVault>, amount: u64, _secret_key: String) -> Result<()> {
+pub fn fund_vault(ctx: Context<FundVault>, amount: u64, _kind: VaultKind) -> Result<()> {
     require!(amount > 0, Errors::InvalidAmount);
 
     let cpi_context = CpiContext::new(
         ctx.accounts.system_program.to_account_info(),
         system_program::Transfer {
-            from: ctx.accounts.admin.to_account_info(),
+            from: ctx.accounts.funder.to_account_info(),
             to: ctx.accounts.vault_account.to_account_info(),
         },
     );
@@ -19,16 +19,21 @@ pub fn fund_vault(ctx: Context<FundVault>, amount: u64, _secret_key: String) ->
 }
 
 #[derive(Accounts)]
-#[instruction(amount: u64, _secret_key: String)]
+#[instruction(amount: u64, _kind: VaultKind)]
 pub struct FundVault<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub funder: Signer<'info>,
     #[account(
         mut,
-        seeds = [b"vault_account", admin.key().as_ref(), _secret_key.as_bytes()],
+        seeds = [b"vault", &[_kind as u8]],
         bump = vault_account.bump,
-        constraint = vault_account.owner == admin.key() @ Errors::Unauthorized,
     )]  
     pub vault_account: Account<'info, VaultAccount>,
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Vault) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
     pub system_program: Program<'info, System>,
-}
\ No newline at end of file
+}
@@ -1,33 +1,39 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::ADMIN_PUBKEY, errors::Errors, state::VaultAccount};
+use crate::{errors::Errors, state::{PauseCategory, ProgramConfig, VaultAccount, VaultKind}};
 
-pub fn initialize_vault(ctx: Context<InitializeVault>, _secret_key: String) -> Result<()> {
-    require!(ctx.accounts.admin.key() == ADMIN_PUBKEY, Errors::Unauthorized);
+pub fn initialize_vault(ctx: Context<InitializeVault>, kind: VaultKind) -> Result<()> {
+    require!(ctx.accounts.admin.key() == ctx.accounts.config.admin, Errors::Unauthorized);
     let vault_account = &mut ctx.accounts.vault_account;
-    vault_account.owner = ctx.accounts.admin.key();
-    let (_vault_account_key, bump) = Pubkey::find_program_address(
-        &[b"vault_account", ctx.accounts.admin.key().as_ref(), _secret_key.as_bytes()],
-        ctx.program_id,
-    );
-    vault_account.bump = bump;
+    vault_account.kind = kind;
+    vault_account.bump = ctx.bumps.vault_account;
+    vault_account.liabilities = 0;
 
-    msg!("Vault account initialized");
+    ctx.accounts.config.vaults[kind as usize] = vault_account.key();
+
+    msg!("{:?} vault account initialized", kind);
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(secret_key: String)]
+#[instruction(kind: VaultKind)]
 pub struct InitializeVault<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
     #[account(
-        init_if_needed,
+        mut,
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Vault) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    #[account(
+        init,
         payer = admin,
-        space = 8 + 32 + 1,
-        seeds = [b"vault_account", admin.key().as_ref(), secret_key.as_bytes()],
+        space = 8 + VaultAccount::SIZE,
+        seeds = [b"vault".as_ref(), &[kind as u8]],
         bump
     )]
     pub vault_account: Account<'info, VaultAccount>,
     pub system_program: Program<'info, System>,
-}
\ No newline at end of file
+}
@@ -0,0 +1,58 @@
+use anchor_lang::{prelude::*, system_program, Discriminator};
+
+use crate::{errors::Errors, state::{PauseCategory, ProgramConfig, VaultAccount, VaultKind}};
+
+// Legacy vaults were addressed by `[b"vault_account", admin, secret_key]`. This drains one into the
+// rental treasury and closes it, so the secret key only has to be supplied this one last time.
+pub fn migrate_legacy_vault(ctx: Context<MigrateLegacyVault>, _secret_key: String) -> Result<()> {
+    require!(ctx.accounts.admin.key() == ctx.accounts.config.admin, Errors::Unauthorized);
+
+    let legacy_vault = &ctx.accounts.legacy_vault;
+    require!(legacy_vault.owner == ctx.program_id, Errors::InvalidLegacyVault);
+    require!(
+        legacy_vault.try_borrow_data()?.starts_with(VaultAccount::DISCRIMINATOR),
+        Errors::InvalidLegacyVault
+    );
+
+    let amount = legacy_vault.lamports();
+    **ctx.accounts.vault_account.to_account_info().try_borrow_mut_lamports()? += amount;
+    **legacy_vault.try_borrow_mut_lamports()? = 0;
+
+    legacy_vault.assign(&system_program::ID);
+    legacy_vault.resize(0)?;
+
+    msg!("Migrated {} lamports from legacy vault {}", amount, legacy_vault.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_secret_key: String)]
+pub struct MigrateLegacyVault<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Vault) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// CHECK: Admin key the legacy vault was derived from
+    pub legacy_owner: UncheckedAccount<'info>,
+
+    /// CHECK: Legacy vault PDA, verified by seeds and discriminator
+    #[account(
+        mut,
+        seeds = [b"vault_account", legacy_owner.key().as_ref(), _secret_key.as_bytes()],
+        bump,
+    )]
+    pub legacy_vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", &[VaultKind::RentalTreasury as u8]],
+        bump = vault_account.bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+}
@@ -1,21 +1,57 @@
 pub mod initialize_vault;
 pub mod transfer_to_vault_and_rent;
-pub mod transfer_from_vault;
+pub mod transfer_to_vault_and_rent_token;
+pub mod extend_rental;
+pub mod extend_rental_token;
+pub mod early_terminate_rental;
+pub mod early_terminate_rental_token;
 pub mod end_rental_session;
+pub mod expire_rental;
 pub mod fund_vault;
 pub mod withdraw_funds;
 pub mod start_rental_with_escrow;
 pub mod finalize_rental_escrow;
 pub mod top_up_escrow;
+pub mod start_rental_with_escrow_token;
+pub mod finalize_rental_escrow_token;
+pub mod top_up_escrow_token;
 pub mod force_terminate_rental;
+pub mod close_rental_session;
+pub mod reclaim_escrow_vault;
+pub mod migrate_legacy_vault;
+pub mod propose_withdrawal;
+pub mod approve_withdrawal;
+pub mod execute_withdrawal;
+pub mod execute_token_withdrawal;
+pub mod cancel_withdrawal;
+pub mod approve_policy_change;
+pub mod apply_policy_change;
 
 pub use initialize_vault::*;
 pub use transfer_to_vault_and_rent::*;
-pub use transfer_from_vault::*;
+pub use transfer_to_vault_and_rent_token::*;
+pub use extend_rental::*;
+pub use extend_rental_token::*;
+pub use early_terminate_rental::*;
+pub use early_terminate_rental_token::*;
 pub use end_rental_session::*;
+pub use expire_rental::*;
 pub use fund_vault::*;
 pub use withdraw_funds::*;
 pub use start_rental_with_escrow::*;
 pub use finalize_rental_escrow::*;
 pub use top_up_escrow::*;
-pub use force_terminate_rental::*;
\ No newline at end of file
+pub use start_rental_with_escrow_token::*;
+pub use finalize_rental_escrow_token::*;
+pub use top_up_escrow_token::*;
+pub use force_terminate_rental::*;
+pub use close_rental_session::*;
+pub use reclaim_escrow_vault::*;
+pub use migrate_legacy_vault::*;
+pub use propose_withdrawal::*;
+pub use approve_withdrawal::*;
+pub use execute_withdrawal::*;
+pub use execute_token_withdrawal::*;
+pub use cancel_withdrawal::*;
+pub use approve_policy_change::*;
+pub use apply_policy_change::*;
\ No newline at end of file
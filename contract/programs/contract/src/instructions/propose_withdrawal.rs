@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::Errors, state::{PauseCategory, ProgramConfig, ProposalStatus, TreasuryPolicy, VaultKind, WithdrawalProposal}};
+
+pub fn propose_withdrawal(
+    ctx: Context<ProposeWithdrawal>,
+    vault_kind: VaultKind,
+    mint: Option<Pubkey>,
+    amount: u64,
+    destination: Pubkey,
+) -> Result<()> {
+    require!(amount > 0, Errors::InvalidAmount);
+
+    let policy = &mut ctx.accounts.treasury_policy;
+    let proposer = ctx.accounts.proposer.key();
+    require!(policy.is_signer(&proposer), Errors::NotTreasurySigner);
+
+    let now = Clock::get()?.unix_timestamp;
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.index = policy.proposal_count;
+    proposal.proposer = proposer;
+    proposal.vault_kind = vault_kind;
+    proposal.mint = mint;
+    proposal.amount = amount;
+    proposal.destination = destination;
+    proposal.approvals = vec![proposer];
+    proposal.created_at = now;
+    proposal.executable_at = now.checked_add(policy.timelock_seconds)
+        .ok_or(Errors::ArithmeticOverflow)?;
+    proposal.status = ProposalStatus::Pending;
+    proposal.bump = ctx.bumps.proposal;
+
+    policy.proposal_count = policy.proposal_count.checked_add(1)
+        .ok_or(Errors::ArithmeticOverflow)?;
+
+    msg!(
+        "Withdrawal {} proposed: {} of {:?} from {:?} to {}, executable at {}",
+        proposal.index,
+        amount,
+        mint,
+        vault_kind,
+        destination,
+        proposal.executable_at
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeWithdrawal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_policy"],
+        bump = treasury_policy.bump,
+    )]
+    pub treasury_policy: Account<'info, TreasuryPolicy>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + WithdrawalProposal::SIZE,
+        seeds = [b"withdrawal_proposal".as_ref(), &treasury_policy.proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, WithdrawalProposal>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Vault) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    pub system_program: Program<'info, System>,
+}
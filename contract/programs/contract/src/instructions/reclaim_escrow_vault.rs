@@ -0,0 +1,64 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::errors::Errors;
+
+// Finalize and force-terminate already close the escrow session and empty its vault, so this only
+// sweeps lamports sent to the vault PDA afterwards. Only returns the user's own funds, so it stays
+// available while the program is paused.
+pub fn reclaim_escrow_vault(ctx: Context<ReclaimEscrowVault>, _id: String) -> Result<()> {
+    let escrow_vault = &ctx.accounts.escrow_vault;
+    let lamports = escrow_vault.lamports();
+    require!(lamports > 0, Errors::InsufficientFunds);
+
+    let user_key = ctx.accounts.user.key();
+    let admin_key = ctx.accounts.admin.key();
+    let escrow_vault_seeds = &[
+        b"escrow_vault",
+        user_key.as_ref(),
+        admin_key.as_ref(),
+        _id.as_bytes(),
+        &[ctx.bumps.escrow_vault],
+    ];
+    let signer_seeds = &[&escrow_vault_seeds[..]];
+
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+            from: escrow_vault.to_account_info(),
+            to: ctx.accounts.user.to_account_info(),
+        },
+        signer_seeds,
+    );
+    system_program::transfer(cpi_context, lamports)?;
+
+    msg!("Escrow vault {} reclaimed, {} lamports returned", _id, lamports);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_id: String)]
+pub struct ReclaimEscrowVault<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    ///CHECK: Admin account the escrow vault was opened against
+    pub admin: UncheckedAccount<'info>,
+
+    /// CHECK: Escrow session PDA, must already be closed so a live escrow can't be drained
+    #[account(
+        seeds = [b"escrow_session", user.key().as_ref(), _id.as_bytes()],
+        bump,
+        constraint = escrow_session.data_is_empty() @ Errors::AlreadyActive,
+    )]
+    pub escrow_session: UncheckedAccount<'info>,
+
+    /// CHECK: PDA that holds SOL
+    #[account(
+        mut,
+        seeds = [b"escrow_vault", user.key().as_ref(), admin.key().as_ref(), _id.as_bytes()],
+        bump,
+    )]
+    pub escrow_vault: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
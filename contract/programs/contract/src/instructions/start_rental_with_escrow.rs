@@ -1,23 +1,28 @@
+#[allow(deprecated)]
 use anchor_lang::solana_program::system_instruction;
 use anchor_lang::{prelude::*, system_program};
 use anchor_lang::solana_program::program::invoke_signed;
-use crate::state::{EscrowSession, RentalSession};
-use crate::errors::Errors;
+use crate::state::{EscrowSession, HostMachineRegistration, HostStatus, MachineTier, PauseCategory, ProgramConfig, RentalSession, RentalStatus};
+use crate::errors::{DepinErrors, Errors};
 
 pub fn start_rental_with_escrow(
     ctx: Context<StartRentalWithEscrow>,
     amount: u64,
     _id: String,
+    referrer: Option<Pubkey>,
+    machine_type: String,
 ) -> Result<()> {
     require!(amount > 0, Errors::InvalidAmount);
+    require!(referrer != Some(ctx.accounts.payer.key()), Errors::SelfReferral);
 
+    let machine_tier = &ctx.accounts.machine_tier;
     let rental = &mut ctx.accounts.rental_session;
     let escrow_session = &mut ctx.accounts.escrow_session;
     let escrow_vault = &ctx.accounts.escrow_vault;
     let payer = &ctx.accounts.payer;
     let admin = &ctx.accounts.admin;
 
-    require!(!rental.is_active, Errors::AlreadyActive);
+    require!(rental.status.can_transition_to(RentalStatus::Active), Errors::AlreadyActive);
 
     let (_escrow_key, escrow_vault_bump) = Pubkey::find_program_address(
         &[b"escrow_vault", ctx.accounts.payer.key().as_ref(), admin.key().as_ref(),_id.as_bytes()],
@@ -59,12 +64,14 @@ pub fn start_rental_with_escrow(
 
     // Update rental session
     rental.start_time = Clock::get()?.unix_timestamp;
-    rental.is_active = true;
+    rental.status.transition(RentalStatus::Active)?;
     rental.amount_paid = amount;
     rental.user = ctx.accounts.payer.key();
     rental.id = _id.clone();
     rental.duration_seconds = 0;
     rental.end_time = 0;
+    rental.consumed_seconds = 0;
+    rental.mint = None;
 
     let (_rental_session_key, bump) = Pubkey::find_program_address(
         &[b"rental_session", ctx.accounts.payer.key().as_ref(), _id.as_bytes()],
@@ -72,12 +79,28 @@ pub fn start_rental_with_escrow(
     );
     rental.bump = bump;
 
+    // Renting a DePIN host ties the session to it so the payment can be credited on finalize
+    rental.host_machine = None;
+    if let Some(host_machine) = ctx.accounts.host_machine.as_mut() {
+        require!(host_machine.status != HostStatus::Penalized, DepinErrors::HostMachinePenalized);
+        require!(host_machine.status == HostStatus::Online, DepinErrors::HostMachineRegistrationNotActive);
+        require!(!host_machine.occupied, DepinErrors::HostMachineOccupied);
+        require!(host_machine.machine_type == machine_type, Errors::MachineTierMismatch);
+        host_machine.occupied = true;
+        rental.host_machine = Some(host_machine.key());
+    }
+
     // Initialize metadata for escrow session
     escrow_session.amount = amount;
     escrow_session.start_time = Clock::get()?.unix_timestamp;
-    escrow_session.is_active = true;
+    escrow_session.status.transition(RentalStatus::Active)?;
     escrow_session.user = ctx.accounts.payer.key();
     escrow_session.id = _id.clone();
+    escrow_session.referrer = referrer;
+    escrow_session.rate_per_second = machine_tier.rate_per_second;
+    escrow_session.machine_type = machine_type;
+    escrow_session.mint = None;
+    escrow_session.vault_admin = admin.key();
 
     let (_escrow_key, escrow_bump) = Pubkey::find_program_address(
         &[b"escrow_session", ctx.accounts.payer.key().as_ref(), _id.as_bytes()],
@@ -90,12 +113,13 @@ pub fn start_rental_with_escrow(
 }
 
 #[derive(Accounts)]
-#[instruction(amount: u64, _id: String)]
+#[instruction(amount: u64, _id: String, referrer: Option<Pubkey>, machine_type: String)]
 pub struct StartRentalWithEscrow<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    ///CHECK: Admin account for vault transfers
+    ///CHECK: Admin account for vault transfers, bound to the config so force termination can find the vault
+    #[account(address = config.admin @ Errors::Unauthorized)]
     pub admin: UncheckedAccount<'info>,
 
     #[account(
@@ -124,5 +148,21 @@ pub struct StartRentalWithEscrow<'info> {
     )]
     pub escrow_vault: UncheckedAccount<'info>,
 
+    // The tier is derived from the machine type being rented so a cheaper tier can't be swapped in
+    #[account(
+        seeds = [b"machine_tier", machine_type.as_bytes(), Pubkey::default().as_ref()],
+        bump = machine_tier.bump,
+    )]
+    pub machine_tier: Account<'info, MachineTier>,
+
+    #[account(mut)]
+    pub host_machine: Option<Account<'info, HostMachineRegistration>>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Escrow) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
     pub system_program: Program<'info, System>,
 }
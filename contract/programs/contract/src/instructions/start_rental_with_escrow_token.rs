@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+
+use crate::state::{EscrowSession, HostMachineRegistration, HostStatus, MachineTier, PauseCategory, ProgramConfig, RentalSession, RentalStatus};
+use crate::errors::{DepinErrors, Errors};
+
+pub fn start_rental_with_escrow_token(
+    ctx: Context<StartRentalWithEscrowToken>,
+    amount: u64,
+    _id: String,
+    referrer: Option<Pubkey>,
+    machine_type: String,
+) -> Result<()> {
+    require!(amount > 0, Errors::InvalidAmount);
+    require!(referrer != Some(ctx.accounts.payer.key()), Errors::SelfReferral);
+
+    let mint_key = ctx.accounts.mint.key();
+    let machine_tier = &ctx.accounts.machine_tier;
+    require!(ctx.accounts.rental_session.status.can_transition_to(RentalStatus::Active), Errors::AlreadyActive);
+
+    // Lock the tokens in the escrow token account owned by the escrow session. Transfer-fee mints
+    // deliver less than was sent, so the escrow is credited with what actually arrived.
+    let balance_before = ctx.accounts.escrow_token_account.amount;
+    let cpi_context = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        token_interface::TransferChecked {
+            from: ctx.accounts.payer_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.escrow_token_account.to_account_info(),
+            authority: ctx.accounts.payer.to_account_info(),
+        },
+    );
+    token_interface::transfer_checked(cpi_context, amount, ctx.accounts.mint.decimals)?;
+    ctx.accounts.escrow_token_account.reload()?;
+    let received = ctx.accounts.escrow_token_account.amount - balance_before;
+    require!(received > 0, Errors::InvalidAmount);
+
+    let now = Clock::get()?.unix_timestamp;
+    let rental = &mut ctx.accounts.rental_session;
+    rental.start_time = now;
+    rental.status.transition(RentalStatus::Active)?;
+    rental.amount_paid = received;
+    rental.user = ctx.accounts.payer.key();
+    rental.id = _id.clone();
+    rental.duration_seconds = 0;
+    rental.end_time = 0;
+    rental.consumed_seconds = 0;
+    rental.mint = Some(mint_key);
+    rental.bump = ctx.bumps.rental_session;
+
+    // Renting a DePIN host ties the session to it so the payment can be credited on finalize
+    rental.host_machine = None;
+    if let Some(host_machine) = ctx.accounts.host_machine.as_mut() {
+        require!(host_machine.status != HostStatus::Penalized, DepinErrors::HostMachinePenalized);
+        require!(host_machine.status == HostStatus::Online, DepinErrors::HostMachineRegistrationNotActive);
+        require!(!host_machine.occupied, DepinErrors::HostMachineOccupied);
+        require!(host_machine.machine_type == machine_type, Errors::MachineTierMismatch);
+        host_machine.occupied = true;
+        rental.host_machine = Some(host_machine.key());
+    }
+
+    let escrow_session = &mut ctx.accounts.escrow_session;
+    escrow_session.amount = received;
+    escrow_session.start_time = now;
+    escrow_session.status.transition(RentalStatus::Active)?;
+    escrow_session.user = ctx.accounts.payer.key();
+    escrow_session.id = _id.clone();
+    escrow_session.referrer = referrer;
+    escrow_session.rate_per_second = machine_tier.rate_per_second;
+    escrow_session.machine_type = machine_type;
+    escrow_session.mint = Some(mint_key);
+    escrow_session.vault_admin = Pubkey::default();
+    escrow_session.bump = ctx.bumps.escrow_session;
+
+    msg!("Rental started with token escrow. Received: {}, mint: {}, ID: {}", received, mint_key, _id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64, _id: String, referrer: Option<Pubkey>, machine_type: String)]
+pub struct StartRentalWithEscrowToken<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = payer,
+        token::token_program = token_program,
+    )]
+    pub payer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + RentalSession::SIZE,
+        seeds = [b"rental_session", payer.key().as_ref(), _id.as_bytes()],
+        bump
+    )]
+    pub rental_session: Box<Account<'info, RentalSession>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + EscrowSession::SIZE,
+        seeds = [b"escrow_session", payer.key().as_ref(), _id.as_bytes()],
+        bump
+    )]
+    pub escrow_session: Box<Account<'info, EscrowSession>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [b"escrow_token", payer.key().as_ref(), _id.as_bytes()],
+        bump,
+        token::mint = mint,
+        token::authority = escrow_session,
+        token::token_program = token_program,
+    )]
+    pub escrow_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // The tier is derived from the machine type being rented so a cheaper tier can't be swapped in
+    #[account(
+        seeds = [b"machine_tier", machine_type.as_bytes(), mint.key().as_ref()],
+        bump = machine_tier.bump,
+    )]
+    pub machine_tier: Box<Account<'info, MachineTier>>,
+
+    #[account(mut)]
+    pub host_machine: Option<Box<Account<'info, HostMachineRegistration>>>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Escrow) @ Errors::ProgramPaused,
+        constraint = config.is_mint_allowed(&mint.key()) @ Errors::MintNotAllowed,
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
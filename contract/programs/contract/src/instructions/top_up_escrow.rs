@@ -1,16 +1,17 @@
 use anchor_lang::{prelude::*, system_program};
 
-use crate::{errors::Errors, state::{EscrowSession, RentalSession}};
+use crate::{errors::Errors, state::{EscrowSession, PauseCategory, ProgramConfig, RentalSession, RentalStatus}};
 
 pub fn top_up_escrow(ctx: Context<TopUpEscrow>, _id: String, amount: u64) -> Result<()> {
     let escrow_session = &mut ctx.accounts.escrow_session;
     let user = &mut ctx.accounts.user;
     let rental_session = &mut ctx.accounts.rental_session;
 
-    require!(escrow_session.is_active, Errors::EscrowNotActive);
+    require!(escrow_session.status == RentalStatus::Active, Errors::EscrowNotActive);
     require!(escrow_session.user == user.key(), Errors::Unauthorized);
     require!(escrow_session.id == _id, Errors::EscrowNotFound);
-    require!(rental_session.is_active, Errors::NotActive);
+    require!(escrow_session.mint.is_none(), Errors::PaymentMintMismatch);
+    require!(rental_session.status == RentalStatus::Active, Errors::NotActive);
     require!(amount > 0, Errors::InvalidAmount);
     
     // Verify the escrow vault address
@@ -73,5 +74,11 @@ pub struct TopUpEscrow<'info> {
         bump,
     )]
     pub escrow_vault: AccountInfo<'info>,
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Escrow) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
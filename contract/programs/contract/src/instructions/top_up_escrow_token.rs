@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+
+use crate::{errors::Errors, state::{EscrowSession, PauseCategory, ProgramConfig, RentalSession, RentalStatus}};
+
+pub fn top_up_escrow_token(ctx: Context<TopUpEscrowToken>, _id: String, amount: u64) -> Result<()> {
+    let escrow_session = &ctx.accounts.escrow_session;
+    require!(escrow_session.status == RentalStatus::Active, Errors::EscrowNotActive);
+    require!(escrow_session.user == ctx.accounts.user.key(), Errors::Unauthorized);
+    require!(escrow_session.id == _id, Errors::EscrowNotFound);
+    require!(escrow_session.mint == Some(ctx.accounts.mint.key()), Errors::PaymentMintMismatch);
+    require!(ctx.accounts.rental_session.status == RentalStatus::Active, Errors::NotActive);
+    require!(amount > 0, Errors::InvalidAmount);
+
+    // Credit only what reached the escrow so transfer fees aren't counted as prepaid time
+    let balance_before = ctx.accounts.escrow_token_account.amount;
+    let cpi_context = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        token_interface::TransferChecked {
+            from: ctx.accounts.user_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.escrow_token_account.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+        },
+    );
+    token_interface::transfer_checked(cpi_context, amount, ctx.accounts.mint.decimals)?;
+    ctx.accounts.escrow_token_account.reload()?;
+    let received = ctx.accounts.escrow_token_account.amount - balance_before;
+
+    let escrow_session = &mut ctx.accounts.escrow_session;
+    escrow_session.amount = escrow_session.amount.checked_add(received)
+        .ok_or(Errors::ArithmeticOverflow)?;
+
+    let rental_session = &mut ctx.accounts.rental_session;
+    rental_session.amount_paid = rental_session.amount_paid.checked_add(received)
+        .ok_or(Errors::ArithmeticOverflow)?;
+
+    msg!("Token escrow topped up successfully. Received: {}", received);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(_id: String)]
+pub struct TopUpEscrowToken<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = user,
+        token::token_program = token_program,
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"rental_session", user.key().as_ref(), _id.as_bytes()],
+        bump = rental_session.bump,
+    )]
+    pub rental_session: Account<'info, RentalSession>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow_session", user.key().as_ref(), _id.as_bytes()],
+        bump = escrow_session.bump,
+    )]
+    pub escrow_session: Account<'info, EscrowSession>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow_token", user.key().as_ref(), _id.as_bytes()],
+        bump,
+        token::mint = mint,
+        token::authority = escrow_session,
+        token::token_program = token_program,
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Escrow) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
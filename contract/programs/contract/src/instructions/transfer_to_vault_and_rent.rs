@@ -2,18 +2,19 @@ use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 
 use crate::errors::Errors;
-use crate::state::RentalSession;
-use crate::state::VaultAccount;
+use crate::state::{PauseCategory, ProgramConfig, RentalSession, RentalStatus};
+use crate::state::{VaultAccount, VaultKind};
 
 pub fn transfer_to_vault_and_rent(
     ctx: Context<TransferToVaultAndRent>, 
     amount: u64, 
     duration_seconds: i64,
     id: String,
-    _secret_key: String,
 ) -> Result<()> {
     require!(amount > 0, Errors::InvalidAmount);
     require!(duration_seconds > 0, Errors::InvalidDuration);
+    // Re-using the id of a live session would wipe its payment and timing; extend_rental covers that
+    require!(ctx.accounts.rental_session.status.can_transition_to(RentalStatus::Active), Errors::AlreadyActive);
     
     let cpi_context = CpiContext::new(
         ctx.accounts.system_program.to_account_info(),
@@ -23,15 +24,19 @@ pub fn transfer_to_vault_and_rent(
         },
     );
     system_program::transfer(cpi_context, amount)?;
+    ctx.accounts.vault_account.add_liability(amount)?;
     
     let rental_session = &mut ctx.accounts.rental_session;
     rental_session.user = ctx.accounts.payer.key();
     rental_session.amount_paid = amount;
     rental_session.start_time = Clock::get()?.unix_timestamp;
     rental_session.duration_seconds = duration_seconds;
-    rental_session.is_active = true;
+    rental_session.status.transition(RentalStatus::Active)?;
     rental_session.id = id;
     rental_session.end_time = rental_session.start_time + duration_seconds;
+    rental_session.consumed_seconds = 0;
+    rental_session.mint = None;
+    rental_session.host_machine = None;
 
     let borrowd_id = rental_session.id.as_bytes();
 
@@ -46,30 +51,32 @@ pub fn transfer_to_vault_and_rent(
 }
 
 #[derive(Accounts)]
-#[instruction(amount: u64, duration_seconds: i64, id: String, _secret_key: String)]
+#[instruction(amount: u64, duration_seconds: i64, id: String)]
 pub struct TransferToVaultAndRent<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [b"vault_account", admin.key().as_ref(), _secret_key.as_bytes()],
+        seeds = [b"vault", &[VaultKind::RentalTreasury as u8]],
         bump = vault_account.bump,
-        constraint = vault_account.owner == admin.key() @ Errors::Unauthorized,
-    )]  
+    )]
     pub vault_account: Account<'info, VaultAccount>,
 
-    ///CHECK: Admin account for vault transfers
-    pub admin: UncheckedAccount<'info>,
-
     #[account(
         init_if_needed,
         payer = payer,
-        space = 8 + 32 + 40 + 8 + 8 + 8 + 8 + 1 + 1,
+        space = 8 + RentalSession::SIZE,
         seeds = [b"rental_session", payer.key().as_ref(), id.as_bytes()],
         bump
     )]
     pub rental_session: Account<'info, RentalSession>,
 
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Rentals) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
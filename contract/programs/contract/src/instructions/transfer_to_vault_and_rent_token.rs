@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+
+use crate::errors::Errors;
+use crate::state::{PauseCategory, ProgramConfig, RentalSession, RentalStatus, TokenLedger};
+use crate::state::{VaultAccount, VaultKind};
+
+pub fn transfer_to_vault_and_rent_token(
+    ctx: Context<TransferToVaultAndRentToken>,
+    amount: u64,
+    duration_seconds: i64,
+    id: String,
+) -> Result<()> {
+    require!(amount > 0, Errors::InvalidAmount);
+    require!(duration_seconds > 0, Errors::InvalidDuration);
+    require!(ctx.accounts.rental_session.status.can_transition_to(RentalStatus::Active), Errors::AlreadyActive);
+
+    // Transfer-fee mints deliver less than was sent, so the rental is credited with what actually arrived
+    let balance_before = ctx.accounts.treasury_token_account.amount;
+    let cpi_context = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        token_interface::TransferChecked {
+            from: ctx.accounts.payer_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.treasury_token_account.to_account_info(),
+            authority: ctx.accounts.payer.to_account_info(),
+        },
+    );
+    token_interface::transfer_checked(cpi_context, amount, ctx.accounts.mint.decimals)?;
+    ctx.accounts.treasury_token_account.reload()?;
+    let received = ctx.accounts.treasury_token_account.amount - balance_before;
+    require!(received > 0, Errors::InvalidAmount);
+
+    // Prepaid tokens stay refundable until the rental ends, so the treasurer can't withdraw them
+    let token_ledger = &mut ctx.accounts.token_ledger;
+    token_ledger.mint = ctx.accounts.mint.key();
+    token_ledger.bump = ctx.bumps.token_ledger;
+    token_ledger.add_liability(received)?;
+
+    let rental_session = &mut ctx.accounts.rental_session;
+    rental_session.user = ctx.accounts.payer.key();
+    rental_session.amount_paid = received;
+    rental_session.start_time = Clock::get()?.unix_timestamp;
+    rental_session.duration_seconds = duration_seconds;
+    rental_session.status.transition(RentalStatus::Active)?;
+    rental_session.id = id;
+    rental_session.end_time = rental_session.start_time + duration_seconds;
+    rental_session.consumed_seconds = 0;
+    rental_session.host_machine = None;
+    rental_session.mint = Some(ctx.accounts.mint.key());
+    rental_session.bump = ctx.bumps.rental_session;
+
+    msg!(
+        "Transferred {} of mint {} to treasury and initialized rental session for user: {}",
+        received,
+        ctx.accounts.mint.key(),
+        ctx.accounts.payer.key()
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64, duration_seconds: i64, id: String)]
+pub struct TransferToVaultAndRentToken<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = payer,
+        token::token_program = token_program,
+    )]
+    pub payer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [b"vault", &[VaultKind::RentalTreasury as u8]],
+        bump = vault_account.bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = vault_account,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + TokenLedger::SIZE,
+        seeds = [b"token_ledger", mint.key().as_ref()],
+        bump
+    )]
+    pub token_ledger: Account<'info, TokenLedger>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + RentalSession::SIZE,
+        seeds = [b"rental_session", payer.key().as_ref(), id.as_bytes()],
+        bump
+    )]
+    pub rental_session: Account<'info, RentalSession>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Rentals) @ Errors::ProgramPaused,
+        constraint = config.is_mint_allowed(&mint.key()) @ Errors::MintNotAllowed,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
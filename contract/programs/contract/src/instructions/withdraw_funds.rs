@@ -1,32 +1,47 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::ADMIN_PUBKEY, errors::Errors, state::VaultAccount};
+use crate::{errors::Errors, state::{OperatorRole, PauseCategory, ProgramConfig, Role, TreasuryPolicy, VaultAccount, VaultKind}};
 
-pub fn withdraw_funds(ctx: Context<WithdrawFunds>, amount: u64, _secret_key: String) -> Result<()> {
-    require!(ctx.accounts.admin.key() == ADMIN_PUBKEY, Errors::Unauthorized);
-    
-    let vault_account = &mut ctx.accounts.vault_account;
+pub fn withdraw_funds(ctx: Context<WithdrawFunds>, amount: u64, _kind: VaultKind) -> Result<()> {
+    ctx.accounts.treasury_policy.record_withdrawal(amount, Clock::get()?.unix_timestamp)?;
+
+    let vault_account = &ctx.accounts.vault_account;
     let vault_balance = vault_account.to_account_info().lamports();
-    require!(vault_balance >= amount, Errors::InsufficientFunds);
-    require!(vault_account.owner == ctx.accounts.admin.key(), Errors::Unauthorized);
+    require!(vault_account.free_balance(vault_balance)? >= amount, Errors::ExceedsFreeBalance);
 
-    **ctx.accounts.admin.to_account_info().try_borrow_mut_lamports()? += amount;
+    **ctx.accounts.treasurer.to_account_info().try_borrow_mut_lamports()? += amount;
     **ctx.accounts.vault_account.to_account_info().try_borrow_mut_lamports()? -= amount;
 
-    msg!("Transferred {} lamports to admin", amount);
+    msg!("Transferred {} lamports to treasurer", amount);
     Ok(())
 }
 #[derive(Accounts)]
-#[instruction(amount: u64, _secret_key: String)]
+#[instruction(amount: u64, _kind: VaultKind)]
 pub struct WithdrawFunds<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub treasurer: Signer<'info>,
+    #[account(
+        seeds = [b"operator_role", treasurer.key().as_ref(), &[Role::Treasurer as u8]],
+        bump = operator_role.bump,
+    )]
+    pub operator_role: Account<'info, OperatorRole>,
+    #[account(
+        seeds = [b"program_config"],
+        bump = config.bump,
+        constraint = !config.is_paused(PauseCategory::Vault) @ Errors::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
     #[account(
         mut,
-        seeds = [b"vault_account", admin.key().as_ref(), _secret_key.as_bytes()],
-        constraint = vault_account.owner == admin.key() @ Errors::Unauthorized,
+        seeds = [b"treasury_policy"],
+        bump = treasury_policy.bump,
+    )]
+    pub treasury_policy: Account<'info, TreasuryPolicy>,
+    #[account(
+        mut,
+        seeds = [b"vault", &[_kind as u8]],
         bump = vault_account.bump,
     )]
     pub vault_account: Account<'info, VaultAccount>,
     pub system_program: Program<'info, System>,
-}
\ No newline at end of file
+}
@@ -3,115 +3,371 @@ use anchor_lang::prelude::*;
 pub mod instructions;
 pub mod state;
 pub mod errors;
-pub mod constants;
 pub mod depin;
+pub mod admin;
+pub mod constants;
+pub mod events;
 
 use instructions::*;
 use depin::*;
+use admin::*;
+use state::{PauseCategory, Role, VaultKind};
 
 declare_id!("423HDGsdSEMLnuPXsggRY2d3YHLGw9ijtAczh3pkCkAs");
 
-#[program]
-pub mod contract {
+// The `#[program]` expansion calls the deprecated `AccountInfo::realloc` in its generated IDL
+// handlers, which land next to the module rather than inside it, so the allow wraps the expansion.
+#[allow(deprecated)]
+mod program_entry {
     use super::*;
 
-    pub fn initialize_vault(ctx: Context<InitializeVault>, secret_key: String) -> Result<()> {
-        instructions::initialize_vault(ctx, secret_key)
-    }
+    #[program]
+    pub mod contract {
+        use super::*;
 
-    pub fn transfer_to_vault_and_rent(
-        ctx: Context<TransferToVaultAndRent>, 
-        amount: u64, 
-        duration_seconds: i64,
-        id: String,
-        secret_key: String
-    ) -> Result<()> {
-        instructions::transfer_to_vault_and_rent(ctx, amount, duration_seconds, id, secret_key)
-    }
+        pub fn initialize_config(ctx: Context<InitializeConfig>, admin: Pubkey) -> Result<()> {
+            admin::initialize_config(ctx, admin)
+        }
 
-    pub fn transfer_from_vault(ctx: Context<TransferFromVault>, amount: u64, id: String, secret_key: String) -> Result<()> {
-        instructions::transfer_from_vault(ctx, amount, id, secret_key)
-    }
+        pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+            admin::propose_admin(ctx, new_admin)
+        }
 
-    pub fn end_rental_session(ctx: Context<EndRentalSession>, id: String, _user_pub_key: Pubkey) -> Result<()> {
-        instructions::end_rental_session(ctx, id, _user_pub_key)
-    }
+        pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+            admin::accept_admin(ctx)
+        }
 
-    pub fn fund_vault(ctx: Context<FundVault>, amount: u64, secret_key: String) -> Result<()> {
-        instructions::fund_vault(ctx, amount, secret_key)
-    }
+        pub fn grant_role(ctx: Context<GrantRole>, operator: Pubkey, role: Role) -> Result<()> {
+            admin::grant_role(ctx, operator, role)
+        }
 
-    pub fn withdraw_funds(ctx: Context<WithdrawFunds>, amount: u64, secret_key: String) -> Result<()> {
-        instructions::withdraw_funds(ctx, amount, secret_key)
-    }
+        pub fn revoke_role(ctx: Context<RevokeRole>, operator: Pubkey, role: Role) -> Result<()> {
+            admin::revoke_role(ctx, operator, role)
+        }
 
-    pub fn start_rental_with_escrow(
-        ctx: Context<StartRentalWithEscrow>, 
-        amount: u64, 
-        id: String
-    ) -> Result<()> {
-        instructions::start_rental_with_escrow(ctx, amount, id)
-    }
+        pub fn set_pause(ctx: Context<SetPause>, category: PauseCategory, paused: bool) -> Result<()> {
+            admin::set_pause(ctx, category, paused)
+        }
 
-    pub fn finalise_rental_with_escrow(
-        ctx: Context<FinalizeRentalEscrow>, 
-        id: String, 
-        amount: u64,
-        secret_key: String
-    ) -> Result<()> {
-        instructions::finalize_rental_escrow(ctx, id, amount, secret_key)
-    }
+        pub fn set_treasury_policy(
+            ctx: Context<SetTreasuryPolicy>,
+            signers: Vec<Pubkey>,
+            threshold: u8,
+            timelock_seconds: i64,
+            withdrawal_limit: u64
+        ) -> Result<()> {
+            admin::set_treasury_policy(ctx, signers, threshold, timelock_seconds, withdrawal_limit)
+        }
 
-    pub fn top_up_escrow(ctx: Context<TopUpEscrow>, id: String, amount: u64) -> Result<()> {
-        instructions::top_up_escrow(ctx, id, amount)
-    }
+        pub fn set_fee_config(
+            ctx: Context<SetFeeConfig>,
+            protocol_fee_bps: u16,
+            referrer_fee_bps: u16,
+            cancellation_fee_bps: u16,
+        ) -> Result<()> {
+            admin::set_fee_config(ctx, protocol_fee_bps, referrer_fee_bps, cancellation_fee_bps)
+        }
 
-    pub fn force_terminate_rental(
-        ctx: Context<ForceTerminateRental>, 
-        id: String, 
-        secret_key: String
-    ) -> Result<()> {
-        instructions::force_terminate_rental(ctx, id, secret_key)
-    }
+        pub fn set_machine_tier(
+            ctx: Context<SetMachineTier>,
+            machine_type: String,
+            price_mint: Pubkey,
+            rate_per_second: u64,
+            min_stake: u64,
+        ) -> Result<()> {
+            admin::set_machine_tier(ctx, machine_type, price_mint, rate_per_second, min_stake)
+        }
 
-    pub fn initialise_host_registration(
-        ctx: Context<InitialiseHostRegistration>, 
-        id: String,
-        host_name: String,
-        machine_type: String,
-        os: String,
-        disk_size: u64,
-        sol_per_hour: u64
-    ) -> Result<()> {
-        depin::initialise_host_registration(ctx, id, host_name, machine_type, os, disk_size, sol_per_hour)
-    }
+        pub fn set_unbonding_period(ctx: Context<SetUnbondingPeriod>, unbonding_period: i64) -> Result<()> {
+            admin::set_unbonding_period(ctx, unbonding_period)
+        }
 
-    pub fn activate_host(
-        ctx: Context<ActivateHost>, 
-        id: String, 
-    ) -> Result<()> {
-        depin::activate_host(ctx, id)
-    }
+        pub fn set_appeal_window(ctx: Context<SetAppealWindow>, appeal_window: i64) -> Result<()> {
+            admin::set_appeal_window(ctx, appeal_window)
+        }
 
-    pub fn deactivate_host(
-        ctx: Context<DeactivateHost>, 
-        id: String, 
-    ) -> Result<()> {
-        depin::deactivate_host(ctx, id)
-    }
+        pub fn set_max_heartbeat_gap(ctx: Context<SetMaxHeartbeatGap>, max_heartbeat_gap: i64) -> Result<()> {
+            admin::set_max_heartbeat_gap(ctx, max_heartbeat_gap)
+        }
 
-    pub fn claim_rewards(
-        ctx: Context<ClaimRewards>, 
-        id: String, 
-        secret_key: String
-    ) -> Result<()> {
-        depin::claim_rewards(ctx, id, secret_key)
-    }
+        pub fn set_expiry_bounty(ctx: Context<SetExpiryBounty>, expiry_bounty: u64) -> Result<()> {
+            admin::set_expiry_bounty(ctx, expiry_bounty)
+        }
+
+        pub fn set_allowed_mints(ctx: Context<SetAllowedMints>, mints: Vec<Pubkey>) -> Result<()> {
+            admin::set_allowed_mints(ctx, mints)
+        }
+
+        pub fn initialize_vault(ctx: Context<InitializeVault>, kind: VaultKind) -> Result<()> {
+            instructions::initialize_vault(ctx, kind)
+        }
+
+        pub fn migrate_legacy_vault(ctx: Context<MigrateLegacyVault>, secret_key: String) -> Result<()> {
+            instructions::migrate_legacy_vault(ctx, secret_key)
+        }
+
+        pub fn transfer_to_vault_and_rent(
+            ctx: Context<TransferToVaultAndRent>, 
+            amount: u64, 
+            duration_seconds: i64,
+            id: String,
+        ) -> Result<()> {
+            instructions::transfer_to_vault_and_rent(ctx, amount, duration_seconds, id)
+        }
+
+        pub fn transfer_to_vault_and_rent_token(
+            ctx: Context<TransferToVaultAndRentToken>,
+            amount: u64,
+            duration_seconds: i64,
+            id: String,
+        ) -> Result<()> {
+            instructions::transfer_to_vault_and_rent_token(ctx, amount, duration_seconds, id)
+        }
+
+        pub fn extend_rental(ctx: Context<ExtendRental>, id: String, amount: u64, additional_seconds: i64) -> Result<()> {
+            instructions::extend_rental(ctx, id, amount, additional_seconds)
+        }
+
+        pub fn early_terminate_rental(ctx: Context<EarlyTerminateRental>, id: String) -> Result<()> {
+            instructions::early_terminate_rental(ctx, id)
+        }
+
+        pub fn extend_rental_token(
+            ctx: Context<ExtendRentalToken>,
+            id: String,
+            amount: u64,
+            additional_seconds: i64,
+        ) -> Result<()> {
+            instructions::extend_rental_token(ctx, id, amount, additional_seconds)
+        }
+
+        pub fn early_terminate_rental_token(ctx: Context<EarlyTerminateRentalToken>, id: String) -> Result<()> {
+            instructions::early_terminate_rental_token(ctx, id)
+        }
+
+        pub fn end_rental_session(ctx: Context<EndRentalSession>, id: String, _user_pub_key: Pubkey) -> Result<()> {
+            instructions::end_rental_session(ctx, id, _user_pub_key)
+        }
+
+        pub fn expire_rental(ctx: Context<ExpireRental>, id: String, user: Pubkey) -> Result<()> {
+            instructions::expire_rental(ctx, id, user)
+        }
+
+        pub fn fund_vault(ctx: Context<FundVault>, amount: u64, kind: VaultKind) -> Result<()> {
+            instructions::fund_vault(ctx, amount, kind)
+        }
+
+        pub fn withdraw_funds(ctx: Context<WithdrawFunds>, amount: u64, kind: VaultKind) -> Result<()> {
+            instructions::withdraw_funds(ctx, amount, kind)
+        }
+
+        pub fn propose_withdrawal(
+            ctx: Context<ProposeWithdrawal>,
+            vault_kind: VaultKind,
+            mint: Option<Pubkey>,
+            amount: u64,
+            destination: Pubkey
+        ) -> Result<()> {
+            instructions::propose_withdrawal(ctx, vault_kind, mint, amount, destination)
+        }
+
+        pub fn approve_withdrawal(ctx: Context<ApproveWithdrawal>, index: u64) -> Result<()> {
+            instructions::approve_withdrawal(ctx, index)
+        }
+
+        pub fn execute_withdrawal(ctx: Context<ExecuteWithdrawal>, index: u64) -> Result<()> {
+            instructions::execute_withdrawal(ctx, index)
+        }
 
-    pub fn penalize_host(
-        ctx: Context<PenalizeHost>, 
-        id: String, 
-    ) -> Result<()> {
-        depin::penalize_host(ctx, id)
+        pub fn execute_token_withdrawal(ctx: Context<ExecuteTokenWithdrawal>, index: u64) -> Result<()> {
+            instructions::execute_token_withdrawal(ctx, index)
+        }
+
+        pub fn cancel_withdrawal(ctx: Context<CancelWithdrawal>, index: u64) -> Result<()> {
+            instructions::cancel_withdrawal(ctx, index)
+        }
+
+        pub fn approve_policy_change(ctx: Context<ApprovePolicyChange>) -> Result<()> {
+            instructions::approve_policy_change(ctx)
+        }
+
+        pub fn apply_policy_change(ctx: Context<ApplyPolicyChange>) -> Result<()> {
+            instructions::apply_policy_change(ctx)
+        }
+
+        pub fn start_rental_with_escrow(
+            ctx: Context<StartRentalWithEscrow>, 
+            amount: u64, 
+            id: String,
+            referrer: Option<Pubkey>,
+            machine_type: String,
+        ) -> Result<()> {
+            instructions::start_rental_with_escrow(ctx, amount, id, referrer, machine_type)
+        }
+
+        pub fn finalise_rental_with_escrow(
+            ctx: Context<FinalizeRentalEscrow>, 
+            id: String, 
+        ) -> Result<()> {
+            instructions::finalize_rental_escrow(ctx, id)
+        }
+
+        pub fn top_up_escrow(ctx: Context<TopUpEscrow>, id: String, amount: u64) -> Result<()> {
+            instructions::top_up_escrow(ctx, id, amount)
+        }
+
+        pub fn start_rental_with_escrow_token(
+            ctx: Context<StartRentalWithEscrowToken>,
+            amount: u64,
+            id: String,
+            referrer: Option<Pubkey>,
+            machine_type: String,
+        ) -> Result<()> {
+            instructions::start_rental_with_escrow_token(ctx, amount, id, referrer, machine_type)
+        }
+
+        pub fn top_up_escrow_token(ctx: Context<TopUpEscrowToken>, id: String, amount: u64) -> Result<()> {
+            instructions::top_up_escrow_token(ctx, id, amount)
+        }
+
+        pub fn finalise_rental_with_escrow_token(ctx: Context<FinalizeRentalEscrowToken>, id: String) -> Result<()> {
+            instructions::finalize_rental_escrow_token(ctx, id)
+        }
+
+        pub fn force_terminate_rental(
+            ctx: Context<ForceTerminateRental>, 
+            id: String, 
+        ) -> Result<()> {
+            instructions::force_terminate_rental(ctx, id)
+        }
+
+        pub fn close_rental_session(ctx: Context<CloseRentalSession>, id: String) -> Result<()> {
+            instructions::close_rental_session(ctx, id)
+        }
+
+        pub fn reclaim_escrow_vault(ctx: Context<ReclaimEscrowVault>, id: String) -> Result<()> {
+            instructions::reclaim_escrow_vault(ctx, id)
+        }
+
+        pub fn initialise_host_registration(
+            ctx: Context<InitialiseHostRegistration>, 
+            id: String,
+            host_name: String,
+            machine_type: String,
+            os: String,
+            disk_size: u64,
+            sol_per_hour: u64
+        ) -> Result<()> {
+            depin::initialise_host_registration(ctx, id, host_name, machine_type, os, disk_size, sol_per_hour)
+        }
+
+        pub fn register_host(
+            ctx: Context<RegisterHost>,
+            id: String,
+            host_name: String,
+            machine_type: String,
+            os: String,
+            disk_size: u64,
+            sol_per_hour: u64
+        ) -> Result<()> {
+            depin::register_host(ctx, id, host_name, machine_type, os, disk_size, sol_per_hour)
+        }
+
+        pub fn approve_host(ctx: Context<ApproveHost>, id: String) -> Result<()> {
+            depin::approve_host(ctx, id)
+        }
+
+        pub fn reject_host(ctx: Context<RejectHost>, id: String) -> Result<()> {
+            depin::reject_host(ctx, id)
+        }
+
+        pub fn activate_host(
+            ctx: Context<ActivateHost>, 
+            id: String, 
+        ) -> Result<()> {
+            depin::activate_host(ctx, id)
+        }
+
+        pub fn deactivate_host(
+            ctx: Context<DeactivateHost>, 
+            id: String, 
+        ) -> Result<()> {
+            depin::deactivate_host(ctx, id)
+        }
+
+        pub fn update_host(
+            ctx: Context<UpdateHost>,
+            id: String,
+            host_name: String,
+            machine_type: String,
+            os: String,
+            disk_size: u64,
+            sol_per_hour: u64
+        ) -> Result<()> {
+            depin::update_host(ctx, id, host_name, machine_type, os, disk_size, sol_per_hour)
+        }
+
+        pub fn set_host_keys(ctx: Context<SetHostKeys>, id: String, operator: Pubkey, payout_wallet: Pubkey) -> Result<()> {
+            depin::set_host_keys(ctx, id, operator, payout_wallet)
+        }
+
+        pub fn host_heartbeat(ctx: Context<HostHeartbeat>, id: String) -> Result<()> {
+            depin::host_heartbeat(ctx, id)
+        }
+
+        pub fn checkpoint_rewards(ctx: Context<CheckpointRewards>, id: String) -> Result<()> {
+            depin::checkpoint_rewards(ctx, id)
+        }
+
+        pub fn claim_rewards(
+            ctx: Context<ClaimRewards>, 
+            id: String, 
+        ) -> Result<()> {
+            depin::claim_rewards(ctx, id)
+        }
+
+        pub fn slash_host(
+            ctx: Context<SlashHost>,
+            id: String,
+            slash_bps: u16,
+            evidence_hash: [u8; 32],
+        ) -> Result<()> {
+            depin::slash_host(ctx, id, slash_bps, evidence_hash)
+        }
+
+        pub fn appeal_slash(
+            ctx: Context<AppealSlash>,
+            id: String,
+            index: u64,
+            counter_evidence_hash: [u8; 32],
+        ) -> Result<()> {
+            depin::appeal_slash(ctx, id, index, counter_evidence_hash)
+        }
+
+        pub fn reverse_slash(ctx: Context<ReverseSlash>, id: String, index: u64) -> Result<()> {
+            depin::reverse_slash(ctx, id, index)
+        }
+
+        pub fn settle_slash(ctx: Context<SettleSlash>, id: String, index: u64) -> Result<()> {
+            depin::settle_slash(ctx, id, index)
+        }
+
+        pub fn reinstate_host(ctx: Context<ReinstateHost>, id: String) -> Result<()> {
+            depin::reinstate_host(ctx, id)
+        }
+
+        pub fn stake_host(ctx: Context<StakeHost>, id: String, amount: u64) -> Result<()> {
+            depin::stake_host(ctx, id, amount)
+        }
+
+        pub fn request_unstake(ctx: Context<RequestUnstake>, id: String, amount: u64) -> Result<()> {
+            depin::request_unstake(ctx, id, amount)
+        }
+
+        pub fn withdraw_stake(ctx: Context<WithdrawStake>, id: String) -> Result<()> {
+            depin::withdraw_stake(ctx, id)
+        }
     }
-}
\ No newline at end of file
+}
+
+pub use program_entry::*;
@@ -1,15 +1,37 @@
 use anchor_lang::prelude::*;
 
+use super::{MachineTier, RentalStatus};
+
 #[account]
 pub struct EscrowSession {
     pub start_time: i64,
-    pub is_active: bool,
+    pub status: RentalStatus,
     pub amount: u64,
     pub bump: u8,
     pub user: Pubkey,
     pub id: String,
+    pub referrer: Option<Pubkey>,
+    pub rate_per_second: u64,
+    /// SPL mint the escrow is held in, `None` for native SOL.
+    pub mint: Option<Pubkey>,
+    /// Machine type whose tier rate the escrow is billed at.
+    pub machine_type: String,
+    /// Admin key the SOL escrow vault was derived from, kept so the vault stays reachable after an admin rotation.
+    pub vault_admin: Pubkey,
 }
 
 impl EscrowSession {
-    pub const SIZE: usize = 8 + 1 + 8 + 1 + 32 + 40; 
+    pub const SIZE: usize = 8 + 1 + 8 + 1 + 32 + 40 + 1 + 32 + 8 + 1 + 32 + 4 + MachineTier::MAX_MACHINE_TYPE_LEN + 32;
+
+    /// Charge for the time elapsed since the session started, capped at the escrowed amount.
+    pub fn metered_charge(&self, now: i64) -> u64 {
+        let elapsed = now.saturating_sub(self.start_time).max(0) as u128;
+        let charge = elapsed.saturating_mul(self.rate_per_second as u128);
+        charge.min(self.amount as u128) as u64
+    }
+
+    /// Whether the metered charge has used up the whole escrow.
+    pub fn is_exhausted(&self, now: i64) -> bool {
+        self.metered_charge(now) >= self.amount
+    }
 }
\ No newline at end of file
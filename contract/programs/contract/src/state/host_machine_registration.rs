@@ -1,8 +1,12 @@
 use anchor_lang::prelude::*;
 
+use super::{HostStatus, MachineTier};
+use crate::{constants::SECONDS_PER_HOUR, errors::{DepinErrors, Errors}};
+
 #[account]
+#[derive(Default)]
 pub struct HostMachineRegistration {
-    pub is_active: bool,
+    pub status: HostStatus,
     pub host_key: Pubkey,
     pub host_name: String,
     pub machine_type: String,
@@ -12,10 +16,83 @@ pub struct HostMachineRegistration {
     pub id: String,
     pub earned: u64,
     pub started_at: i64,
-    pub penalized: bool,
     pub sol_per_hour: u64,
+    pub occupied: bool,
+    pub slash_count: u64,
+    pub total_slashed: u64,
+    pub last_heartbeat: i64,
+    // Online time proven by heartbeats since the last accrual.
+    pub covered_seconds: u64,
+    // Sub-lamport leftover of `covered_seconds * sol_per_hour`, in lamport-seconds per hour.
+    pub reward_carry: u64,
+    // Hot key on the machine that runs day-to-day operations, so the owner key can stay offline.
+    pub operator: Pubkey,
+    pub payout_wallet: Pubkey,
 }
 
 impl HostMachineRegistration {
-    pub const SIZE: usize = 1 + 32 + 4 + 32 + 4 + 32 + 4 + 32 + 8 + 1 + 32 + 8 + 8 + 1 + 8;
+    pub const MAX_ID_LEN: usize = 32;
+    pub const MAX_HOST_NAME_LEN: usize = 64;
+    pub const MAX_OS_LEN: usize = 32;
+
+    /// Bounds the string fields so the account stays a predictable size.
+    pub fn validate_fields(id: &str, host_name: &str, machine_type: &str, os: &str) -> Result<()> {
+        require!(
+            !id.is_empty() && id.len() <= Self::MAX_ID_LEN,
+            DepinErrors::InvalidHostMachineRegistrationId
+        );
+        require!(
+            !host_name.is_empty() && host_name.len() <= Self::MAX_HOST_NAME_LEN,
+            DepinErrors::InvalidHostMachineRegistrationName
+        );
+        require!(
+            !machine_type.is_empty() && machine_type.len() <= MachineTier::MAX_MACHINE_TYPE_LEN,
+            DepinErrors::InvalidHostMachineRegistrationData
+        );
+        require!(
+            !os.is_empty() && os.len() <= Self::MAX_OS_LEN,
+            DepinErrors::InvalidHostMachineRegistrationOS
+        );
+        Ok(())
+    }
+
+    /// Exact account size, discriminator included, for the given string fields.
+    pub fn space(id: &str, host_name: &str, machine_type: &str, os: &str) -> usize {
+        8 + 1 + 32 + 8 + 1 + 8 + 8 + 8 + 1 + 8 + 8 + 8 + 8 + 8 + 32 + 32
+            + 4 + id.len()
+            + 4 + host_name.len()
+            + 4 + machine_type.len()
+            + 4 + os.len()
+    }
+
+    pub fn is_operated_by(&self, key: &Pubkey) -> bool {
+        *key == self.host_key || *key == self.operator
+    }
+
+    /// Credits the time since the previous heartbeat, unless the host went quiet for longer than `max_gap`.
+    pub fn record_heartbeat(&mut self, now: i64, max_gap: i64) -> Result<u64> {
+        let gap = now.saturating_sub(self.last_heartbeat);
+        let credited = if gap > 0 && gap <= max_gap { gap as u64 } else { 0 };
+        self.covered_seconds = self.covered_seconds
+            .checked_add(credited)
+            .ok_or(Errors::ArithmeticOverflow)?;
+        self.last_heartbeat = now;
+        Ok(credited)
+    }
+
+    /// Converts covered seconds into lamports at per-second precision, carrying the fraction forward.
+    pub fn accrue_rewards(&mut self) -> Result<u64> {
+        let accrued = (self.covered_seconds as u128)
+            .checked_mul(self.sol_per_hour as u128)
+            .and_then(|total| total.checked_add(self.reward_carry as u128))
+            .ok_or(Errors::ArithmeticOverflow)?;
+        let reward = u64::try_from(accrued / SECONDS_PER_HOUR as u128)
+            .map_err(|_| Errors::ArithmeticOverflow)?;
+        self.reward_carry = (accrued % SECONDS_PER_HOUR as u128) as u64;
+        self.covered_seconds = 0;
+        self.earned = self.earned
+            .checked_add(reward)
+            .ok_or(Errors::ArithmeticOverflow)?;
+        Ok(reward)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct HostStake {
+    pub host_machine: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub unbonding_amount: u64,
+    pub unbonding_at: i64,
+    pub bump: u8,
+}
+
+impl HostStake {
+    pub const SIZE: usize = 32 + 32 + 8 + 8 + 8 + 1;
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::Errors;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum RentalStatus {
+    #[default]
+    Pending,
+    Active,
+    Expired,
+    Finalized,
+    Terminated,
+    Disputed,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum HostStatus {
+    #[default]
+    Registered,
+    Online,
+    Offline,
+    Penalized,
+    Retired,
+    // Self-submitted registration waiting for a host registrar's approval
+    Pending,
+}
+
+impl RentalStatus {
+    pub fn can_transition_to(self, next: RentalStatus) -> bool {
+        use RentalStatus::*;
+        matches!(
+            (self, next),
+            (Pending, Active)
+                | (Active, Expired | Finalized | Terminated | Disputed)
+                | (Disputed, Finalized | Terminated)
+                // A settled session id can be reused for a new rental
+                | (Expired | Finalized | Terminated, Active)
+        )
+    }
+
+    pub fn transition(&mut self, next: RentalStatus) -> Result<()> {
+        require!(self.can_transition_to(next), Errors::InvalidStatusTransition);
+        *self = next;
+        Ok(())
+    }
+
+    pub fn is_settled(self) -> bool {
+        matches!(self, RentalStatus::Expired | RentalStatus::Finalized | RentalStatus::Terminated)
+    }
+}
+
+impl HostStatus {
+    pub fn can_transition_to(self, next: HostStatus) -> bool {
+        use HostStatus::*;
+        matches!(
+            (self, next),
+            (Pending, Registered)
+                | (Registered | Offline, Online)
+                | (Online, Offline)
+                | (Registered | Online | Offline, Penalized)
+                | (Penalized, Offline)
+                | (Registered | Offline | Penalized, Retired)
+        )
+    }
+
+    pub fn transition(&mut self, next: HostStatus) -> Result<()> {
+        require!(self.can_transition_to(next), Errors::InvalidStatusTransition);
+        *self = next;
+        Ok(())
+    }
+}
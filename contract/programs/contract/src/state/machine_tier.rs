@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct MachineTier {
+    pub machine_type: String,
+    /// Mint the rate is quoted in, `Pubkey::default()` for native SOL.
+    pub price_mint: Pubkey,
+    pub rate_per_second: u64,
+    /// Lamports a host of this machine type must stake before going online.
+    pub min_stake: u64,
+    pub bump: u8,
+}
+
+impl MachineTier {
+    pub const MAX_MACHINE_TYPE_LEN: usize = 32;
+    pub const SIZE: usize = 4 + 32 + 32 + 8 + 8 + 1;
+}
@@ -2,8 +2,24 @@ pub mod vault_account;
 pub mod rental_session;
 pub mod escrow_session;
 pub mod host_machine_registration;
+pub mod program_config;
+pub mod operator_role;
+pub mod treasury_policy;
+pub mod machine_tier;
+pub mod lifecycle;
+pub mod host_stake;
+pub mod slash_record;
+pub mod token_ledger;
 
 pub use vault_account::*;
 pub use rental_session::*;
 pub use escrow_session::*;
-pub use host_machine_registration::*;
\ No newline at end of file
+pub use host_machine_registration::*;
+pub use program_config::*;
+pub use operator_role::*;
+pub use treasury_policy::*;
+pub use machine_tier::*;
+pub use lifecycle::*;
+pub use host_stake::*;
+pub use slash_record::*;
+pub use token_ledger::*;
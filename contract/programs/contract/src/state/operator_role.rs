@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    Treasurer,
+    HostRegistrar,
+    Slasher,
+    SessionOperator,
+    Guardian,
+    Arbiter,
+    Pinger,
+}
+
+#[account]
+pub struct OperatorRole {
+    pub operator: Pubkey,
+    pub role: Role,
+    pub bump: u8,
+}
+
+impl OperatorRole {
+    pub const SIZE: usize = 32 + 1 + 1;
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::BPS_DENOMINATOR, errors::Errors};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PauseCategory {
+    Rentals,
+    Escrow,
+    Depin,
+    Vault,
+}
+
+#[account]
+pub struct ProgramConfig {
+    pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>,
+    pub paused: u8,
+    pub vaults: [Pubkey; 4],
+    pub protocol_fee_bps: u16,
+    pub referrer_fee_bps: u16,
+    pub cancellation_fee_bps: u16,
+    pub expiry_bounty: u64,
+    pub allowed_mints: Vec<Pubkey>,
+    pub unbonding_period: i64,
+    pub appeal_window: i64,
+    pub max_heartbeat_gap: i64,
+    pub bump: u8,
+}
+
+pub struct SettlementSplit {
+    pub host_payout: u64,
+    pub protocol_fee: u64,
+    pub referrer_fee: u64,
+}
+
+pub fn apply_bps(amount: u64, bps: u16) -> u64 {
+    (amount as u128 * bps as u128 / BPS_DENOMINATOR as u128) as u64
+}
+
+impl ProgramConfig {
+    pub const SIZE: usize = 32 + 1 + 32 + 1 + 32 * 4 + 2 + 2 + 2 + 8 + 4 + 32 * Self::MAX_ALLOWED_MINTS + 8 + 8 + 8 + 1;
+    pub const MAX_ALLOWED_MINTS: usize = 5;
+
+    pub fn is_paused(&self, category: PauseCategory) -> bool {
+        self.paused & (1 << category as u8) != 0
+    }
+
+    pub fn set_paused(&mut self, category: PauseCategory, paused: bool) {
+        if paused {
+            self.paused |= 1 << category as u8;
+        } else {
+            self.paused &= !(1 << category as u8);
+        }
+    }
+
+    pub fn is_mint_allowed(&self, mint: &Pubkey) -> bool {
+        self.allowed_mints.contains(mint)
+    }
+
+    pub fn settlement_split(&self, amount: u64, has_referrer: bool) -> Result<SettlementSplit> {
+        let protocol_fee = apply_bps(amount, self.protocol_fee_bps);
+        let referrer_fee = if has_referrer { apply_bps(amount, self.referrer_fee_bps) } else { 0 };
+        let host_payout = amount
+            .checked_sub(protocol_fee)
+            .and_then(|rest| rest.checked_sub(referrer_fee))
+            .ok_or(Errors::ArithmeticOverflow)?;
+
+        Ok(SettlementSplit { host_payout, protocol_fee, referrer_fee })
+    }
+}
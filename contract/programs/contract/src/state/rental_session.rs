@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 
+use super::RentalStatus;
+use crate::errors::Errors;
+
 #[account]
 pub struct RentalSession {
     pub user: Pubkey,
@@ -8,10 +11,45 @@ pub struct RentalSession {
     pub start_time: i64,
     pub end_time: i64,
     pub duration_seconds: i64,
-    pub is_active: bool,
+    pub status: RentalStatus,
     pub bump: u8,
+    pub host_machine: Option<Pubkey>,
+    pub consumed_seconds: i64,
+    /// SPL mint the rental was paid in, `None` for native SOL.
+    pub mint: Option<Pubkey>,
 }
 
 impl RentalSession {
-    pub const SIZE: usize = 32 + 40 + 8 + 8 + 8 + 8 + 1 + 1;
+    pub const SIZE: usize = 32 + 40 + 8 + 8 + 8 + 8 + 1 + 1 + 1 + 32 + 8 + 1 + 32;
+
+    /// Seconds left on a prepaid rental at `now`, never more than its full duration.
+    pub fn remaining_seconds(&self, now: i64) -> i64 {
+        (self.end_time - now).min(self.duration_seconds)
+    }
+
+    /// Share of the prepayment covering the time still left at `now`.
+    pub fn unused_amount(&self, now: i64) -> u64 {
+        (self.amount_paid as u128 * self.remaining_seconds(now) as u128
+            / self.duration_seconds as u128) as u64
+    }
+
+    /// Pushes the end forward but keeps the original start so the session history stays intact.
+    pub fn extend(&mut self, amount: u64, additional_seconds: i64) -> Result<()> {
+        self.amount_paid = self.amount_paid.checked_add(amount)
+            .ok_or(Errors::ArithmeticOverflow)?;
+        self.duration_seconds = self.duration_seconds.checked_add(additional_seconds)
+            .ok_or(Errors::ArithmeticOverflow)?;
+        self.end_time = self.end_time.checked_add(additional_seconds)
+            .ok_or(Errors::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Ends the rental at `now`, recording the time actually used.
+    pub fn terminate_early(&mut self, now: i64) -> Result<()> {
+        self.consumed_seconds = self.duration_seconds - self.remaining_seconds(now);
+        self.end_time = now;
+        self.status.transition(RentalStatus::Terminated)?;
+        self.amount_paid = 0;
+        Ok(())
+    }
 }
\ No newline at end of file
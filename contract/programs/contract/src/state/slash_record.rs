@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct SlashRecord {
+    pub host_machine: Pubkey,
+    pub index: u64,
+    pub slasher: Pubkey,
+    pub slash_bps: u16,
+    /// Digest of the off-chain evidence, e.g. a failed health-check report.
+    pub evidence_hash: [u8; 32],
+    pub stake_slashed: u64,
+    /// Portion of `stake_slashed` taken from unbonding collateral, restored there on reversal.
+    pub unbonding_slashed: u64,
+    pub earnings_slashed: u64,
+    pub created_at: i64,
+    /// Counter-evidence digest submitted by the host within the appeal window.
+    pub appeal_hash: Option<[u8; 32]>,
+    pub appealed_at: i64,
+    /// Set once an arbiter has returned the slashed funds.
+    pub reversed: bool,
+    /// Set once the slashed funds stop being held against an appeal, either returned or released to insurance.
+    pub settled: bool,
+    pub bump: u8,
+}
+
+impl SlashRecord {
+    pub const SIZE: usize = 32 + 8 + 32 + 2 + 32 + 8 + 8 + 8 + 8 + 1 + 32 + 8 + 1 + 1 + 1;
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::Errors;
+
+// Token counterpart of `VaultAccount::liabilities` for rental payments held in the treasury's ATA of `mint`.
+#[account]
+pub struct TokenLedger {
+    pub mint: Pubkey,
+    pub liabilities: u64,
+    pub bump: u8,
+}
+
+impl TokenLedger {
+    pub const SIZE: usize = 32 + 8 + 1;
+
+    pub fn add_liability(&mut self, amount: u64) -> Result<()> {
+        self.liabilities = self.liabilities.checked_add(amount)
+            .ok_or(Errors::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn release_liability(&mut self, amount: u64) {
+        self.liabilities = self.liabilities.saturating_sub(amount);
+    }
+
+    // Tokens that can leave the treasury without touching prepaid rentals.
+    pub fn free_balance(&self, held: u64) -> u64 {
+        held.saturating_sub(self.liabilities)
+    }
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::WITHDRAWAL_WINDOW, errors::Errors, state::VaultKind};
+
+#[account]
+pub struct TreasuryPolicy {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timelock_seconds: i64,
+    pub withdrawal_limit: u64,
+    /// Direct withdrawals made since `window_start`, capped at `withdrawal_limit` per window.
+    pub withdrawn_in_window: u64,
+    pub window_start: i64,
+    pub proposal_count: u64,
+    pub bump: u8,
+    /// Staged replacement policy, applied once the current signers approve it and the timelock passes.
+    pub pending_change: Option<PolicyChange>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct PolicyChange {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timelock_seconds: i64,
+    pub withdrawal_limit: u64,
+    pub approvals: Vec<Pubkey>,
+    pub executable_at: i64,
+}
+
+impl PolicyChange {
+    pub const SIZE: usize = 4 + 32 * TreasuryPolicy::MAX_SIGNERS + 1 + 8 + 8 + 4 + 32 * TreasuryPolicy::MAX_SIGNERS + 8;
+}
+
+impl TreasuryPolicy {
+    pub const MAX_SIGNERS: usize = 10;
+    pub const SIZE: usize = 4 + 32 * Self::MAX_SIGNERS + 1 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + PolicyChange::SIZE;
+
+    pub fn is_signer(&self, key: &Pubkey) -> bool {
+        self.signers.contains(key)
+    }
+
+    // Splitting a withdrawal across calls can't get past the limit, anything larger goes through a proposal.
+    pub fn record_withdrawal(&mut self, amount: u64, now: i64) -> Result<()> {
+        if now.saturating_sub(self.window_start) >= WITHDRAWAL_WINDOW {
+            self.window_start = now;
+            self.withdrawn_in_window = 0;
+        }
+        let withdrawn = self.withdrawn_in_window.checked_add(amount)
+            .ok_or(Errors::ArithmeticOverflow)?;
+        require!(withdrawn <= self.withdrawal_limit, Errors::WithdrawalAboveLimit);
+        self.withdrawn_in_window = withdrawn;
+        Ok(())
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        !self.signers.is_empty()
+    }
+
+    // Approvals from keys that have since been removed from the policy no longer count.
+    pub fn approval_count(&self, approvals: &[Pubkey]) -> usize {
+        approvals.iter().filter(|key| self.is_signer(key)).count()
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProposalStatus {
+    Pending,
+    Executed,
+    Cancelled,
+}
+
+#[account]
+pub struct WithdrawalProposal {
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub vault_kind: VaultKind,
+    /// Token withdrawn from the vault's ATA, `None` for lamports.
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    pub destination: Pubkey,
+    pub approvals: Vec<Pubkey>,
+    pub created_at: i64,
+    pub executable_at: i64,
+    pub status: ProposalStatus,
+    pub bump: u8,
+}
+
+impl WithdrawalProposal {
+    pub const SIZE: usize = 8 + 32 + 1 + 1 + 32 + 8 + 32 + 4 + 32 * TreasuryPolicy::MAX_SIGNERS + 8 + 8 + 1 + 1;
+
+    pub fn approval_count(&self, policy: &TreasuryPolicy) -> usize {
+        policy.approval_count(&self.approvals)
+    }
+}
@@ -1,7 +1,41 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::Errors;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VaultKind {
+    RentalTreasury,
+    RewardsPool,
+    FeePool,
+    Insurance,
+}
+
 #[account]
 pub struct VaultAccount {
-    pub owner: Pubkey,
+    pub kind: VaultKind,
     pub bump: u8,
-}
\ No newline at end of file
+    // Lamports owed back out of this vault: refundable rental payments or unclaimed host rewards.
+    pub liabilities: u64,
+}
+
+impl VaultAccount {
+    pub const SIZE: usize = 1 + 1 + 8;
+
+    pub fn add_liability(&mut self, amount: u64) -> Result<()> {
+        self.liabilities = self.liabilities.checked_add(amount)
+            .ok_or(Errors::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn release_liability(&mut self, amount: u64) {
+        self.liabilities = self.liabilities.saturating_sub(amount);
+    }
+
+    // Lamports that can leave the vault without touching liabilities or the rent-exempt minimum.
+    pub fn free_balance(&self, lamports: u64) -> Result<u64> {
+        let rent_exempt = Rent::get()?.minimum_balance(8 + Self::SIZE);
+        Ok(lamports
+            .saturating_sub(rent_exempt)
+            .saturating_sub(self.liabilities))
+    }
+}
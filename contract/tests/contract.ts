@@ -1,16 +1,30 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { Contract } from "../target/types/contract";
+import {
+  ExtensionType,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  createInitializeMintInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  createMint,
+  getAssociatedTokenAddressSync,
+  getMintLen,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from "@solana/spl-token";
 import assert from "assert";
 
 describe("contract", () => {
   // Configure the client to use the local cluster.
   anchor.setProvider(anchor.AnchorProvider.env());
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
   const user = anchor.web3.Keypair.generate();
-  const admin = anchor.web3.Keypair.generate();
+  // The provider wallet is the upgrade authority on localnet, so it bootstraps the program config as admin.
+  const admin = (provider.wallet as anchor.Wallet).payer;
   let vaultAccount: anchor.web3.PublicKey;
+  let machineTier: anchor.web3.PublicKey;
   const id = "1001";
-  const secretKey = "my_secret_key"; 
 
   const program = anchor.workspace.contract as Program<Contract>;
 
@@ -29,26 +43,101 @@ describe("contract", () => {
     console.log("Airdropped 3 SOL to user account:", user.publicKey.toBase58());
 
     [vaultAccount] = await anchor.web3.PublicKey.findProgramAddressSync(
-      [Buffer.from("vault_account"), admin.publicKey.toBuffer(), Buffer.from(secretKey)],
+      [Buffer.from("vault"), Buffer.from([0])],
       program.programId
     );
     console.log("Vault account address:", vaultAccount.toBase58());
+
+    [machineTier] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("machine_tier"), Buffer.from("e2-medium"), anchor.web3.PublicKey.default.toBuffer()],
+      program.programId
+    );
+  });
+
+  it("initialises program config", async () => {
+    const [configPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("program_config")],
+      program.programId
+    );
+    const existing = await program.account.programConfig.fetchNullable(configPda);
+    if (!existing) {
+      const [programData] = anchor.web3.PublicKey.findProgramAddressSync(
+        [program.programId.toBuffer()],
+        anchor.web3.BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+      );
+      const tx = await program.methods.initializeConfig(admin.publicKey)
+        .accounts({
+          authority: admin.publicKey,
+          programData,
+        })
+        .rpc();
+      console.log("Your transaction signature", tx);
+    }
+    const config = await program.account.programConfig.fetch(configPda);
+    assert.ok(config.admin.equals(admin.publicKey), "Config admin should be the provider wallet");
+  });
+
+  it("hands admin over and back", async () => {
+    const [configPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("program_config")],
+      program.programId
+    );
+    const newAdmin = anchor.web3.Keypair.generate();
+
+    await program.methods.proposeAdmin(newAdmin.publicKey)
+      .accounts({ admin: admin.publicKey })
+      .rpc();
+    await program.methods.acceptAdmin()
+      .accounts({ newAdmin: newAdmin.publicKey })
+      .signers([newAdmin])
+      .rpc();
+    let config = await program.account.programConfig.fetch(configPda);
+    assert.ok(config.admin.equals(newAdmin.publicKey), "Admin should be handed over to the new key");
+
+    await program.methods.proposeAdmin(admin.publicKey)
+      .accounts({ admin: newAdmin.publicKey })
+      .signers([newAdmin])
+      .rpc();
+    await program.methods.acceptAdmin()
+      .accounts({ newAdmin: admin.publicKey })
+      .rpc();
+    config = await program.account.programConfig.fetch(configPda);
+    assert.ok(config.admin.equals(admin.publicKey), "Admin should be handed back");
+    assert.ok(config.pendingAdmin === null, "No handover should be pending");
+  });
+
+  it("grants operator roles", async () => {
+    for (const role of [{ treasurer: {} }, { sessionOperator: {} }, { guardian: {} }]) {
+      await program.methods.grantRole(admin.publicKey, role)
+        .accounts({ admin: admin.publicKey })
+        .rpc();
+    }
+    const [treasurerRole] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("operator_role"), admin.publicKey.toBuffer(), Buffer.from([0])],
+      program.programId
+    );
+    const operatorRole = await program.account.operatorRole.fetch(treasurerRole);
+    assert.ok(operatorRole.operator.equals(admin.publicKey), "Role should be granted to the admin");
   });
 
   it("initalise vault account", async () => {
-    // Add your test here.
-    const tx = await program.methods.initializeVault(secretKey).accounts({
-      admin: admin.publicKey,
-    })
-    .signers([admin])
-    .rpc();
-    console.log("Your transaction signature", tx);
+    const existing = await program.account.vaultAccount.fetchNullable(vaultAccount);
+    if (!existing) {
+      const tx = await program.methods.initializeVault({ rentalTreasury: {} }).accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+      console.log("Your transaction signature", tx);
+    }
+    const vault = await program.account.vaultAccount.fetch(vaultAccount);
+    assert.ok(vault.kind.rentalTreasury !== undefined, "Vault should be registered under its kind");
   });
 
   it("funds vault account", async () => {
     // Add your test here.
-    const tx = await program.methods.fundVault(new anchor.BN(1000000000), secretKey).accounts({
-      admin: admin.publicKey,
+    const tx = await program.methods.fundVault(new anchor.BN(1000000000), { rentalTreasury: {} }).accounts({
+      funder: admin.publicKey,
     })
       .signers([admin])
       .rpc();
@@ -57,6 +146,28 @@ describe("contract", () => {
     assert.ok(vaultAccountBalance > 1, "Vault account should have a balance");
   });
 
+  it("rejects rentals while paused", async () => {
+    await program.methods.setPause({ rentals: {} }, true)
+      .accounts({ guardian: admin.publicKey })
+      .rpc();
+
+    try {
+      await program.methods.transferToVaultAndRent(new anchor.BN(1000000000), new anchor.BN(10), id)
+        .accounts({
+          payer: user.publicKey,
+        })
+        .signers([user])
+        .rpc();
+      assert.fail("Rental should be rejected while paused");
+    } catch (err) {
+      assert.ok(err.toString().includes("ProgramPaused"), "Should fail with ProgramPaused");
+    }
+
+    await program.methods.setPause({ rentals: {} }, false)
+      .accounts({ guardian: admin.publicKey })
+      .rpc();
+  });
+
   it("transfer to vault account and starts rental session", async () => {    
     const [rentalSessionPda] = await anchor.web3.PublicKey.findProgramAddressSync(
       [Buffer.from("rental_session"), user.publicKey.toBuffer(), Buffer.from(id)],
@@ -67,10 +178,8 @@ describe("contract", () => {
         new anchor.BN(1000000000),
         new anchor.BN(10),
         id,
-        secretKey
     )
     .accounts({
-        admin: admin.publicKey,
         payer: user.publicKey,
     })
     .signers([user])
@@ -78,31 +187,66 @@ describe("contract", () => {
 
     console.log("Transaction successful:", tx);
     const rental_session = await program.account.rentalSession.fetch(rentalSessionPda)
-    assert.ok(rental_session.isActive, "Rental session should be active after transfer");
+    assert.ok(rental_session.status.active !== undefined, "Rental session should be active after transfer");
   });
 
-  it("transfers funds from vault account to user account", async () => {
-    const tx = await program.methods.transferFromVault(new anchor.BN(0.5 * anchor.web3.LAMPORTS_PER_SOL), id, secretKey)
+  it("extends the rental session", async () => {
+    const [rentalSessionPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("rental_session"), user.publicKey.toBuffer(), Buffer.from(id)],
+      program.programId
+    );
+    const before = await program.account.rentalSession.fetch(rentalSessionPda);
+    await program.methods.extendRental(id, new anchor.BN(0.1 * anchor.web3.LAMPORTS_PER_SOL), new anchor.BN(10))
+      .accounts({
+        payer: user.publicKey,
+      })
+      .signers([user])
+      .rpc();
+    const after = await program.account.rentalSession.fetch(rentalSessionPda);
+    assert.ok(after.endTime.toNumber() === before.endTime.toNumber() + 10, "End time should move forward");
+    assert.ok(after.startTime.eq(before.startTime), "Start time should be preserved");
+  });
+
+  it("terminates the rental early with a prorated refund", async () => {
+    const [rentalSessionPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("rental_session"), user.publicKey.toBuffer(), Buffer.from(id)],
+      program.programId
+    );
+    const balanceBefore = await anchor.getProvider().connection.getBalance(user.publicKey);
+    const tx = await program.methods.earlyTerminateRental(id)
       .accounts({
-        admin: admin.publicKey,
         payer: user.publicKey,
       })  
       .signers([user])
       .rpc();
     console.log("Your transaction signature", tx);  
-    const vaultAccountBalance = await anchor.getProvider().connection.getBalance(vaultAccount);
-    console.log("Vault account balance after transfer:", vaultAccountBalance);
-    assert.ok(vaultAccountBalance < 2000000000, "Vault account should have a balance after transfer");
+    const balanceAfter = await anchor.getProvider().connection.getBalance(user.publicKey);
+    const rentalSession = await program.account.rentalSession.fetch(rentalSessionPda);
+    assert.ok(balanceAfter < balanceBefore + 1000000000, "Refund should not exceed the unused share");
+    assert.ok(rentalSession.status.terminated !== undefined, "Rental session should be terminated after early termination");
+    assert.ok(rentalSession.consumedSeconds.toNumber() <= 10, "Consumed time should be recorded");
   });
 
   it("ends rental session", async () => {
     const idBuffer = Buffer.alloc(8);
     idBuffer.writeBigUInt64LE(BigInt(id), 0);
 
+    try {
+      await program.methods.endRentalSession(id, user.publicKey).accounts({
+        operator: user.publicKey,
+        tokenLedger: null,
+      })
+      .signers([user])
+      .rpc();
+      assert.fail("Only session operators should end rentals");
+    } catch (err) {
+      assert.ok(err.toString().includes("AccountNotInitialized"), "Should fail without the SessionOperator role");
+    }
+
     const tx = await program.methods.endRentalSession(id, user.publicKey).accounts({
-      payer: user.publicKey,
+      operator: admin.publicKey,
+      tokenLedger: null,
     })
-    .signers([user])
     .rpc();
     console.log("Your transaction signature", tx);
 
@@ -113,13 +257,132 @@ describe("contract", () => {
       )[0]
     );
     console.log("Updated rental session:", updatedRentalSession);
-    assert.ok(updatedRentalSession.isActive === false, "Rental session should be inactive after ending it");
+    assert.ok(updatedRentalSession.status.active === undefined, "Rental session should be inactive after ending it");
+  });
+
+  it("expires a finished rental and pays the crank bounty", async () => {
+    const expiringId = "1002";
+    const [rentalSessionPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("rental_session"), user.publicKey.toBuffer(), Buffer.from(expiringId)],
+      program.programId
+    );
+    await program.methods.setExpiryBounty(new anchor.BN(5000))
+      .accounts({ admin: admin.publicKey })
+      .rpc();
+    await program.methods.transferToVaultAndRent(new anchor.BN(100000000), new anchor.BN(1), expiringId)
+      .accounts({ payer: user.publicKey })
+      .signers([user])
+      .rpc();
+
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+
+    const tx = await program.methods.expireRental(expiringId, user.publicKey)
+      .accounts({ caller: admin.publicKey, tokenLedger: null })
+      .rpc();
+    console.log("Your transaction signature", tx);
+    const rentalSession = await program.account.rentalSession.fetch(rentalSessionPda);
+    assert.ok(rentalSession.status.expired !== undefined, "Rental session should be expired");
+  });
+
+  it("closes the expired rental session", async () => {
+    const [rentalSessionPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("rental_session"), user.publicKey.toBuffer(), Buffer.from("1002")],
+      program.programId
+    );
+    await program.methods.closeRentalSession("1002")
+      .accounts({ user: user.publicKey })
+      .signers([user])
+      .rpc();
+    const rentalSession = await program.account.rentalSession.fetchNullable(rentalSessionPda);
+    assert.ok(rentalSession === null, "Rental session should be closed");
+  });
+
+  it("sets the treasury policy", async () => {
+    const [policyPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury_policy")],
+      program.programId
+    );
+    await program.methods.setTreasuryPolicy(
+      [admin.publicKey],
+      1,
+      new anchor.BN(0),
+      new anchor.BN(anchor.web3.LAMPORTS_PER_SOL)
+    )
+      .accounts({ admin: admin.publicKey })
+      .rpc();
+    const policy = await program.account.treasuryPolicy.fetch(policyPda);
+    assert.ok(policy.threshold === 1, "Threshold should be set");
+  });
+
+  it("stages treasury policy changes until a signer approves them", async () => {
+    const [policyPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury_policy")],
+      program.programId
+    );
+    const raisedLimit = new anchor.BN(2 * anchor.web3.LAMPORTS_PER_SOL);
+    await program.methods.setTreasuryPolicy([admin.publicKey], 1, new anchor.BN(0), raisedLimit)
+      .accounts({ admin: admin.publicKey })
+      .rpc();
+
+    let policy = await program.account.treasuryPolicy.fetch(policyPda);
+    assert.ok(policy.withdrawalLimit.eq(new anchor.BN(anchor.web3.LAMPORTS_PER_SOL)), "Limit should not change before approval");
+    assert.ok(policy.pendingChange !== null, "Change should be staged");
+
+    try {
+      await program.methods.applyPolicyChange()
+        .accounts({ executor: admin.publicKey })
+        .rpc();
+      assert.fail("Should not apply without approvals");
+    } catch (err) {
+      assert.ok(err.toString().includes("ThresholdNotMet"));
+    }
+
+    await program.methods.approvePolicyChange()
+      .accounts({ approver: admin.publicKey })
+      .rpc();
+    await program.methods.applyPolicyChange()
+      .accounts({ executor: admin.publicKey })
+      .rpc();
+
+    policy = await program.account.treasuryPolicy.fetch(policyPda);
+    assert.ok(policy.withdrawalLimit.eq(raisedLimit), "Limit should be applied");
+    assert.ok(policy.pendingChange === null, "Pending change should be cleared");
+  });
+
+  it("proposes and executes a treasury withdrawal", async () => {
+    const [policyPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury_policy")],
+      program.programId
+    );
+    const policy = await program.account.treasuryPolicy.fetch(policyPda);
+    const index = policy.proposalCount;
+    const destination = anchor.web3.Keypair.generate().publicKey;
+    const amount = new anchor.BN(0.1 * anchor.web3.LAMPORTS_PER_SOL);
+
+    await program.methods.proposeWithdrawal({ rentalTreasury: {} }, null, amount, destination)
+      .accounts({ proposer: admin.publicKey })
+      .rpc();
+    await program.methods.executeWithdrawal(index)
+      .accounts({
+        executor: admin.publicKey,
+        destination,
+      })
+      .rpc();
+
+    const [proposalPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("withdrawal_proposal"), index.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const proposal = await program.account.withdrawalProposal.fetch(proposalPda);
+    assert.ok(proposal.status.executed !== undefined, "Proposal should be executed");
+    const destinationBalance = await anchor.getProvider().connection.getBalance(destination);
+    assert.ok(destinationBalance === amount.toNumber(), "Destination should receive the withdrawal");
   });
 
   it("withdraws funds from vault account", async () => {
-    const tx = await program.methods.withdrawFunds(new anchor.BN(0.5 * anchor.web3.LAMPORTS_PER_SOL), secretKey)
+    const tx = await program.methods.withdrawFunds(new anchor.BN(0.5 * anchor.web3.LAMPORTS_PER_SOL), { rentalTreasury: {} })
       .accounts({
-        admin: admin.publicKey,
+        treasurer: admin.publicKey,
       })
       .signers([admin])
       .rpc();
@@ -129,6 +392,102 @@ describe("contract", () => {
     assert.ok(vaultAccountBalance < 1000000000, "Vault account should have a balance after withdrawal");
   });
 
+  it("caps direct withdrawals per window rather than per call", async () => {
+    // 0.5 SOL has already gone out this window, so 1.6 SOL is under the 2 SOL limit alone but not in total
+    try {
+      await program.methods.withdrawFunds(new anchor.BN(1.6 * anchor.web3.LAMPORTS_PER_SOL), { rentalTreasury: {} })
+        .accounts({ treasurer: admin.publicKey })
+        .rpc();
+      assert.fail("Withdrawals past the window limit should be rejected");
+    } catch (err) {
+      assert.ok(err.toString().includes("WithdrawalAboveLimit"), "Should fail with WithdrawalAboveLimit");
+    }
+    const [policyPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury_policy")],
+      program.programId
+    );
+    const policy = await program.account.treasuryPolicy.fetch(policyPda);
+    assert.ok(policy.withdrawnInWindow.eq(new anchor.BN(0.5 * anchor.web3.LAMPORTS_PER_SOL)), "Window total should track direct withdrawals");
+  });
+
+  it("caps withdrawals at the free balance while rentals are outstanding", async () => {
+    // An open duration rental leaves its prepayment owed to the renter
+    await program.methods.transferToVaultAndRent(new anchor.BN(0.5 * anchor.web3.LAMPORTS_PER_SOL), new anchor.BN(3600), "5001")
+      .accounts({ payer: user.publicKey })
+      .signers([user])
+      .rpc();
+
+    const vault = await program.account.vaultAccount.fetch(vaultAccount);
+    assert.ok(vault.liabilities.gtn(0), "Open rental should be recorded as a liability");
+    const vaultInfo = await provider.connection.getAccountInfo(vaultAccount);
+    const rentExempt = await provider.connection.getMinimumBalanceForRentExemption(vaultInfo.data.length);
+    const freeBalance = new anchor.BN(vaultInfo.lamports).subn(rentExempt).sub(vault.liabilities);
+    const overdraw = freeBalance.addn(1);
+
+    try {
+      await program.methods.withdrawFunds(overdraw, { rentalTreasury: {} })
+        .accounts({ treasurer: admin.publicKey })
+        .rpc();
+      assert.fail("Withdrawal into liabilities should be rejected");
+    } catch (err) {
+      assert.ok(err.toString().includes("ExceedsFreeBalance"), "Should fail with ExceedsFreeBalance");
+    }
+
+    const [policyPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury_policy")],
+      program.programId
+    );
+    const index = (await program.account.treasuryPolicy.fetch(policyPda)).proposalCount;
+    const destination = anchor.web3.Keypair.generate().publicKey;
+    await program.methods.proposeWithdrawal({ rentalTreasury: {} }, null, overdraw, destination)
+      .accounts({ proposer: admin.publicKey })
+      .rpc();
+    try {
+      await program.methods.executeWithdrawal(index)
+        .accounts({
+          executor: admin.publicKey,
+          destination,
+        })
+        .rpc();
+      assert.fail("Proposal into liabilities should be rejected");
+    } catch (err) {
+      assert.ok(err.toString().includes("ExceedsFreeBalance"), "Should fail with ExceedsFreeBalance");
+    }
+    await program.methods.cancelWithdrawal(index)
+      .accounts({ signer: admin.publicKey })
+      .rpc();
+  });
+
+  it("sets protocol fees and opens the fee pool", async () => {
+    const [feeVault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), Buffer.from([2])],
+      program.programId
+    );
+    if (!(await program.account.vaultAccount.fetchNullable(feeVault))) {
+      await program.methods.initializeVault({ feePool: {} })
+        .accounts({ admin: admin.publicKey })
+        .rpc();
+    }
+    await program.methods.setFeeConfig(500, 100, 200)
+      .accounts({ admin: admin.publicKey })
+      .rpc();
+    const [configPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("program_config")],
+      program.programId
+    );
+    const config = await program.account.programConfig.fetch(configPda);
+    assert.ok(config.protocolFeeBps === 500, "Protocol fee should be set");
+  });
+
+  it("prices a machine tier", async () => {
+    await program.methods.setMachineTier("e2-medium", anchor.web3.PublicKey.default, new anchor.BN(1000), new anchor.BN(0.1 * anchor.web3.LAMPORTS_PER_SOL))
+      .accounts({ admin: admin.publicKey })
+      .rpc();
+    const tier = await program.account.machineTier.fetch(machineTier);
+    assert.ok(tier.ratePerSecond.toNumber() === 1000, "Machine tier should be priced per second");
+    assert.ok(tier.priceMint.equals(anchor.web3.PublicKey.default), "Machine tier should be priced in SOL");
+  });
+
   it("starts a new rental session with escrow", async () => {
     const [escrowVault, escrowBump] = await anchor.web3.PublicKey.findProgramAddressSync(
       [Buffer.from("escrow_vault"), user.publicKey.toBuffer(), admin.publicKey.toBuffer() ,Buffer.from(id)],
@@ -137,12 +496,16 @@ describe("contract", () => {
     const tx = await program.methods.startRentalWithEscrow(
       new anchor.BN(1 * anchor.web3.LAMPORTS_PER_SOL),
       id,
+      null,
+      "e2-medium",
     )
     .accounts({
       payer: user.publicKey,
       admin: admin.publicKey,
       // @ts-ignore
       escrowVault: escrowVault,
+      machineTier,
+      hostMachine: null,
     })
     .signers([user])
     .rpc();
@@ -151,7 +514,7 @@ describe("contract", () => {
       program.programId
     );
     const rentalSession = await program.account.rentalSession.fetch(rentalSessionPda[0]);
-    assert.ok(rentalSession.isActive, "Rental session should be active after starting with escrow");
+    assert.ok(rentalSession.status.active !== undefined, "Rental session should be active after starting with escrow");
   });
 
   it("top up escrow session", async () => {
@@ -179,18 +542,20 @@ describe("contract", () => {
       program.programId
     );
 
-    const tx = await program.methods.forceTerminateRental(id, secretKey)
+    const tx = await program.methods.forceTerminateRental(id)
       .accounts({
+        operator: admin.publicKey,
         admin: admin.publicKey,
         user: user.publicKey,
+        hostMachine: null,
       })
       .signers([admin])
       .rpc();
 
     console.log("Force terminate transaction signature", tx);
     
-    const escrowSession = await program.account.escrowSession.fetch(escrowSessionPda);
-    assert.ok(!escrowSession.isActive, "Escrow session should be inactive after force termination");
+    const escrowSession = await program.account.escrowSession.fetchNullable(escrowSessionPda);
+    assert.ok(escrowSession === null, "Escrow session should be closed after force termination");
 });
 
   it("finalizes rental escrow", async () => {
@@ -204,23 +569,325 @@ describe("contract", () => {
       program.programId
     );
 
+    // The earlier session was force terminated and closed, so open a fresh one under the same id
+    await program.methods.startRentalWithEscrow(new anchor.BN(0.5 * anchor.web3.LAMPORTS_PER_SOL), id, null, "e2-medium")
+      .accounts({
+        payer: user.publicKey,
+        admin: admin.publicKey,
+        machineTier,
+        hostMachine: null,
+      })
+      .signers([user])
+      .rpc();
+
     const escrow_vault = await anchor.getProvider().connection.getBalance(escrowVault[0]);
     console.log("Escrow account balance before finalization:", escrow_vault / anchor.web3.LAMPORTS_PER_SOL);
 
-    const tx = await program.methods.finaliseRentalWithEscrow(id, new anchor.BN(0.25 * anchor.web3.LAMPORTS_PER_SOL), secretKey)
+    const tx = await program.methods.finaliseRentalWithEscrow(id)
       .accounts({
+        caller: user.publicKey,
         user: user.publicKey,
         admin: admin.publicKey,
+        referrer: null,
+        hostMachine: null,
       })
       .signers([user])
       .rpc();
 
-    const escrowSession = await program.account.escrowSession.fetch(escrowSessionPda[0]);
+    const escrowSession = await program.account.escrowSession.fetchNullable(escrowSessionPda[0]);
     const vaultAccountBalance = await anchor.getProvider().connection.getBalance(vaultAccount);
     console.log("Vault account balance after finalization:", vaultAccountBalance / anchor.web3.LAMPORTS_PER_SOL);
     const escrowVaultBalance = await anchor.getProvider().connection.getBalance(escrowVault[0]);
     console.log("Escrow vault balance after finalization:", escrowVaultBalance / anchor.web3.LAMPORTS_PER_SOL);
     assert.ok(vaultAccountBalance > 2, "Vault account should have a balance after finalization");
-    assert.ok(!escrowSession.isActive, "Escrow session should be inactive after finalization");
+    assert.ok(escrowSession === null, "Escrow session should be closed after finalization");
+    assert.ok(escrowVaultBalance === 0, "Escrow vault rent should be returned to the user");
+  });
+
+  it("reclaims lamports sent to a settled escrow vault", async () => {
+    const [escrowVault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("escrow_vault"), user.publicKey.toBuffer(), admin.publicKey.toBuffer(), Buffer.from(id)],
+      program.programId
+    );
+    const stray = 0.01 * anchor.web3.LAMPORTS_PER_SOL;
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.transfer({ fromPubkey: admin.publicKey, toPubkey: escrowVault, lamports: stray })
+      )
+    );
+
+    const balanceBefore = await provider.connection.getBalance(user.publicKey);
+    await program.methods.reclaimEscrowVault(id)
+      .accounts({
+        user: user.publicKey,
+        admin: admin.publicKey,
+      })
+      .signers([user])
+      .rpc();
+    const balanceAfter = await provider.connection.getBalance(user.publicKey);
+    assert.ok(await provider.connection.getBalance(escrowVault) === 0, "Escrow vault should be emptied");
+    assert.ok(balanceAfter > balanceBefore, "Stray lamports should go back to the user");
+  });
+
+  it("rejects renters referring themselves", async () => {
+    try {
+      await program.methods.startRentalWithEscrow(new anchor.BN(0.1 * anchor.web3.LAMPORTS_PER_SOL), "4001", user.publicKey, "e2-medium")
+        .accounts({
+          payer: user.publicKey,
+          admin: admin.publicKey,
+          machineTier,
+          hostMachine: null,
+        })
+        .signers([user])
+        .rpc();
+      assert.fail("Self-referral should be rejected");
+    } catch (err) {
+      assert.ok(err.toString().includes("SelfReferral"), "Should fail with SelfReferral");
+    }
+  });
+
+  it("rejects escrows opened against a key other than the config admin", async () => {
+    try {
+      await program.methods.startRentalWithEscrow(new anchor.BN(0.1 * anchor.web3.LAMPORTS_PER_SOL), "4002", null, "e2-medium")
+        .accounts({
+          payer: user.publicKey,
+          admin: user.publicKey,
+          machineTier,
+          hostMachine: null,
+        })
+        .signers([user])
+        .rpc();
+      assert.fail("Escrow against a non-admin key should be rejected");
+    } catch (err) {
+      assert.ok(err.toString().includes("Unauthorized"), "Should fail with Unauthorized");
+    }
+  });
+
+  it("rents against a token escrow and settles in the same mint", async () => {
+    const tokenId = "3001";
+    const mint = await createMint(provider.connection, admin, admin.publicKey, null, 6);
+    const userTokenAccount = await getOrCreateAssociatedTokenAccount(provider.connection, admin, mint, user.publicKey);
+    await mintTo(provider.connection, admin, mint, userTokenAccount.address, admin, 10_000_000);
+
+    await program.methods.setAllowedMints([mint])
+      .accounts({ admin: admin.publicKey })
+      .rpc();
+    await program.methods.setMachineTier("e2-medium", mint, new anchor.BN(10), new anchor.BN(0))
+      .accounts({ admin: admin.publicKey })
+      .rpc();
+    const [tokenTier] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("machine_tier"), Buffer.from("e2-medium"), mint.toBuffer()],
+      program.programId
+    );
+
+    await program.methods.startRentalWithEscrowToken(new anchor.BN(5_000_000), tokenId, null, "e2-medium")
+      .accounts({
+        payer: user.publicKey,
+        mint,
+        payerTokenAccount: userTokenAccount.address,
+        machineTier: tokenTier,
+        hostMachine: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+
+    await program.methods.finaliseRentalWithEscrowToken(tokenId)
+      .accounts({
+        caller: user.publicKey,
+        user: user.publicKey,
+        mint,
+        userTokenAccount: userTokenAccount.address,
+        hostMachine: null,
+        hostTokenAccount: null,
+        referrerTokenAccount: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+
+    const balance = await provider.connection.getTokenAccountBalance(userTokenAccount.address);
+    assert.ok(Number(balance.value.amount) > 5_000_000, "Unused token escrow should be refunded");
+    assert.ok(Number(balance.value.amount) < 10_000_000, "Elapsed time should be charged in the token");
+  });
+
+  it("credits a Token-2022 escrow with the amount received after transfer fees", async () => {
+    const feeId = "3002";
+    const feeMint = anchor.web3.Keypair.generate();
+    const mintLen = getMintLen([ExtensionType.TransferFeeConfig]);
+    const lamports = await provider.connection.getMinimumBalanceForRentExemption(mintLen);
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.createAccount({
+          fromPubkey: admin.publicKey,
+          newAccountPubkey: feeMint.publicKey,
+          space: mintLen,
+          lamports,
+          programId: TOKEN_2022_PROGRAM_ID,
+        }),
+        // 1% transfer fee
+        createInitializeTransferFeeConfigInstruction(
+          feeMint.publicKey, admin.publicKey, admin.publicKey, 100, BigInt(1_000_000), TOKEN_2022_PROGRAM_ID
+        ),
+        createInitializeMintInstruction(feeMint.publicKey, 6, admin.publicKey, null, TOKEN_2022_PROGRAM_ID)
+      ),
+      [feeMint]
+    );
+    const userTokenAccount = await getOrCreateAssociatedTokenAccount(
+      provider.connection, admin, feeMint.publicKey, user.publicKey, false, undefined, undefined, TOKEN_2022_PROGRAM_ID
+    );
+    await mintTo(
+      provider.connection, admin, feeMint.publicKey, userTokenAccount.address, admin, 10_000_000, [], undefined, TOKEN_2022_PROGRAM_ID
+    );
+
+    const [configPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("program_config")],
+      program.programId
+    );
+    const config = await program.account.programConfig.fetch(configPda);
+    await program.methods.setAllowedMints([...config.allowedMints, feeMint.publicKey])
+      .accounts({ admin: admin.publicKey })
+      .rpc();
+    await program.methods.setMachineTier("e2-medium", feeMint.publicKey, new anchor.BN(10), new anchor.BN(0))
+      .accounts({ admin: admin.publicKey })
+      .rpc();
+    const [tokenTier] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("machine_tier"), Buffer.from("e2-medium"), feeMint.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods.startRentalWithEscrowToken(new anchor.BN(1_000_000), feeId, null, "e2-medium")
+      .accounts({
+        payer: user.publicKey,
+        mint: feeMint.publicKey,
+        payerTokenAccount: userTokenAccount.address,
+        machineTier: tokenTier,
+        hostMachine: null,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+
+    const [escrowSessionPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("escrow_session"), user.publicKey.toBuffer(), Buffer.from(feeId)],
+      program.programId
+    );
+    const escrowSession = await program.account.escrowSession.fetch(escrowSessionPda);
+    assert.ok(escrowSession.amount.toNumber() === 990_000, "Escrow should record the amount received after the fee");
+    assert.ok(escrowSession.mint.equals(feeMint.publicKey), "Escrow should record its mint");
+
+    await program.methods.finaliseRentalWithEscrowToken(feeId)
+      .accounts({
+        caller: user.publicKey,
+        user: user.publicKey,
+        mint: feeMint.publicKey,
+        userTokenAccount: userTokenAccount.address,
+        hostMachine: null,
+        hostTokenAccount: null,
+        referrerTokenAccount: null,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+    assert.ok(
+      (await program.account.escrowSession.fetchNullable(escrowSessionPda)) === null,
+      "Escrow session should be closed after settling"
+    );
+
+    // Duration rentals accept the same allow-listed Token-2022 mint
+    await program.methods.transferToVaultAndRentToken(new anchor.BN(1_000_000), new anchor.BN(3600), "3004")
+      .accounts({
+        payer: user.publicKey,
+        mint: feeMint.publicKey,
+        payerTokenAccount: userTokenAccount.address,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+    const [feeLedger] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("token_ledger"), feeMint.publicKey.toBuffer()],
+      program.programId
+    );
+    const ledger = await program.account.tokenLedger.fetch(feeLedger);
+    assert.ok(ledger.liabilities.toNumber() === 990_000, "Duration rental should owe what arrived after the fee");
+  });
+
+  it("extends and early-terminates a token duration rental with a prorated refund", async () => {
+    const tokenId = "3003";
+    const mint = await createMint(provider.connection, admin, admin.publicKey, null, 6);
+    const userTokenAccount = await getOrCreateAssociatedTokenAccount(provider.connection, admin, mint, user.publicKey);
+    await mintTo(provider.connection, admin, mint, userTokenAccount.address, admin, 10_000_000);
+    const [configPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("program_config")],
+      program.programId
+    );
+    const config = await program.account.programConfig.fetch(configPda);
+    await program.methods.setAllowedMints([...config.allowedMints.slice(-3), mint])
+      .accounts({ admin: admin.publicKey })
+      .rpc();
+    const [tokenLedger] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("token_ledger"), mint.toBuffer()],
+      program.programId
+    );
+
+    await program.methods.transferToVaultAndRentToken(new anchor.BN(1_000_000), new anchor.BN(3600), tokenId)
+      .accounts({
+        payer: user.publicKey,
+        mint,
+        payerTokenAccount: userTokenAccount.address,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+    await program.methods.extendRentalToken(tokenId, new anchor.BN(1_000_000), new anchor.BN(3600))
+      .accounts({
+        payer: user.publicKey,
+        mint,
+        payerTokenAccount: userTokenAccount.address,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+    let ledger = await program.account.tokenLedger.fetch(tokenLedger);
+    assert.ok(ledger.liabilities.toNumber() === 2_000_000, "Prepaid tokens should be tracked as liabilities");
+
+    await program.methods.earlyTerminateRentalToken(tokenId)
+      .accounts({
+        payer: user.publicKey,
+        mint,
+        payerTokenAccount: userTokenAccount.address,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+    ledger = await program.account.tokenLedger.fetch(tokenLedger);
+    assert.ok(ledger.liabilities.toNumber() === 0, "Liabilities should be released on termination");
+    const balance = await provider.connection.getTokenAccountBalance(userTokenAccount.address);
+    assert.ok(Number(balance.value.amount) > 9_000_000, "Unused rental time should be refunded in the token");
+
+    // The retained cancellation fee is free to leave the treasury through a proposal
+    const treasuryTokenAccount = getAssociatedTokenAddressSync(mint, vaultAccount, true);
+    const retained = new anchor.BN((await provider.connection.getTokenAccountBalance(treasuryTokenAccount)).value.amount);
+    const adminTokenAccount = await getOrCreateAssociatedTokenAccount(provider.connection, admin, mint, admin.publicKey);
+    const [policyPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury_policy")],
+      program.programId
+    );
+    const index = (await program.account.treasuryPolicy.fetch(policyPda)).proposalCount;
+    await program.methods.proposeWithdrawal({ rentalTreasury: {} }, mint, retained, admin.publicKey)
+      .accounts({ proposer: admin.publicKey })
+      .rpc();
+    await program.methods.executeTokenWithdrawal(index)
+      .accounts({
+        executor: admin.publicKey,
+        mint,
+        destinationTokenAccount: adminTokenAccount.address,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+    const withdrawn = await provider.connection.getTokenAccountBalance(adminTokenAccount.address);
+    assert.ok(new anchor.BN(withdrawn.value.amount).eq(retained), "Treasury tokens should reach the destination");
   });
 });
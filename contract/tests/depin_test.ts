@@ -6,12 +6,15 @@ import assert from "assert";
 describe("contract", () => {
   // Configure the client to use the local cluster.
   anchor.setProvider(anchor.AnchorProvider.env());
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
   const user = anchor.web3.Keypair.generate();
-  const admin = anchor.web3.Keypair.generate();
+  const operator = anchor.web3.Keypair.generate();
+  // The provider wallet is the upgrade authority on localnet, so it bootstraps the program config as admin.
+  const admin = (provider.wallet as anchor.Wallet).payer;
   let vaultAccount: anchor.web3.PublicKey;
+  let machineTier: anchor.web3.PublicKey;
   let hostMachine: anchor.web3.PublicKey;
   const id = "1001";
-  const secretKey = "my_secret_key"; 
 
   const program = anchor.workspace.contract as Program<Contract>;
 
@@ -30,11 +33,16 @@ describe("contract", () => {
     console.log("Airdropped 3 SOL to user account:", user.publicKey.toBase58());
 
     [vaultAccount] = await anchor.web3.PublicKey.findProgramAddressSync(
-      [Buffer.from("vault_account"), admin.publicKey.toBuffer(), Buffer.from(secretKey)],
+      [Buffer.from("vault"), Buffer.from([1])],
       program.programId
     );
     console.log("Vault account address:", vaultAccount.toBase58());
 
+    [machineTier] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("machine_tier"), Buffer.from("e2-medium"), anchor.web3.PublicKey.default.toBuffer()],
+      program.programId
+    );
+
     [hostMachine] = await anchor.web3.PublicKey.findProgramAddressSync(
       [Buffer.from("host_machine"), user.publicKey.toBuffer(), Buffer.from(id)],
       program.programId
@@ -42,20 +50,61 @@ describe("contract", () => {
     console.log("Host machine address:", hostMachine.toBase58());
   });
 
+  it("initialises program config", async () => {
+    const [configPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("program_config")],
+      program.programId
+    );
+    const existing = await program.account.programConfig.fetchNullable(configPda);
+    if (!existing) {
+      const [programData] = anchor.web3.PublicKey.findProgramAddressSync(
+        [program.programId.toBuffer()],
+        anchor.web3.BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+      );
+      const tx = await program.methods.initializeConfig(admin.publicKey)
+        .accounts({
+          authority: admin.publicKey,
+          programData,
+        })
+        .rpc();
+      console.log("Your transaction signature", tx);
+    }
+    const config = await program.account.programConfig.fetch(configPda);
+    assert.ok(config.admin.equals(admin.publicKey), "Config admin should be the provider wallet");
+  });
+
+  it("grants operator roles", async () => {
+    for (const role of [{ hostRegistrar: {} }, { slasher: {} }, { arbiter: {} }]) {
+      await program.methods.grantRole(admin.publicKey, role)
+        .accounts({ admin: admin.publicKey })
+        .rpc();
+    }
+    const [treasurerRole] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("operator_role"), admin.publicKey.toBuffer(), Buffer.from([1])],
+      program.programId
+    );
+    const operatorRole = await program.account.operatorRole.fetch(treasurerRole);
+    assert.ok(operatorRole.operator.equals(admin.publicKey), "Role should be granted to the admin");
+  });
+
   it("initalise vault account", async () => {
-    // Add your test here.
-    const tx = await program.methods.initializeVault(secretKey).accounts({
-      admin: admin.publicKey,
-    })
-    .signers([admin])
-    .rpc();
-    console.log("Your transaction signature", tx);
+    const existing = await program.account.vaultAccount.fetchNullable(vaultAccount);
+    if (!existing) {
+      const tx = await program.methods.initializeVault({ rewardsPool: {} }).accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+      console.log("Your transaction signature", tx);
+    }
+    const vault = await program.account.vaultAccount.fetch(vaultAccount);
+    assert.ok(vault.kind.rewardsPool !== undefined, "Vault should be registered under its kind");
   });
 
   it("funds vault account", async () => {
     // Add your test here.
-    const tx = await program.methods.fundVault(new anchor.BN(1000000000), secretKey).accounts({
-      admin: admin.publicKey,
+    const tx = await program.methods.fundVault(new anchor.BN(1000000000), { rewardsPool: {} }).accounts({
+      funder: admin.publicKey,
     })
       .signers([admin])
       .rpc();
@@ -64,6 +113,12 @@ describe("contract", () => {
     assert.ok(vaultAccountBalance > 1, "Vault account should have a balance");
   });
 
+  it("prices a machine tier", async () => {
+    await program.methods.setMachineTier("e2-medium", anchor.web3.PublicKey.default, new anchor.BN(1000), new anchor.BN(0.1 * anchor.web3.LAMPORTS_PER_SOL))
+      .accounts({ admin: admin.publicKey })
+      .rpc();
+  });
+
   it("starts a new rental session with escrow", async () => {
     const [escrowVault, escrowBump] = await anchor.web3.PublicKey.findProgramAddressSync(
       [Buffer.from("escrow_vault"), user.publicKey.toBuffer(), admin.publicKey.toBuffer() ,Buffer.from(id)],
@@ -72,12 +127,16 @@ describe("contract", () => {
     const tx = await program.methods.startRentalWithEscrow(
       new anchor.BN(1 * anchor.web3.LAMPORTS_PER_SOL),
       id,
+      null,
+      "e2-medium",
     )
     .accounts({
       payer: user.publicKey,
       admin: admin.publicKey,
       // @ts-ignore
       escrowVault: escrowVault,
+      machineTier,
+      hostMachine: null,
     })
     .signers([user])
     .rpc();
@@ -86,7 +145,7 @@ describe("contract", () => {
       program.programId
     );
     const rentalSession = await program.account.rentalSession.fetch(rentalSessionPda[0]);
-    assert.ok(rentalSession.isActive, "Rental session should be active after starting with escrow");
+    assert.ok(rentalSession.status.active !== undefined, "Rental session should be active after starting with escrow");
   });
 
   it("top up escrow session", async () => {
@@ -110,31 +169,208 @@ describe("contract", () => {
   it ("initialise host machine", async () => {
     const tx = await program.methods.initialiseHostRegistration(id, "host_name", "e2-medium", "linux", new anchor.BN(100), new anchor.BN(1))
     .accounts({
-        admin: admin.publicKey,
+        registrar: admin.publicKey,
         userKey: user.publicKey,
     })
     .signers([admin])
     .rpc();
     const hostMachineAccount = await program.account.hostMachineRegistration.fetch(hostMachine);
-    assert.ok(hostMachineAccount.isActive === false, "Host machine should be inactive after initialization");
+    assert.ok(hostMachineAccount.status.registered !== undefined, "Host machine should be registered after initialization");
     console.log("Your transaction signature", tx);
   });
 
+  it("registers a host with maximum-length fields and rejects longer ones", async () => {
+    const maxId = "9".repeat(32);
+    const hostName = "h".repeat(64);
+    const machineType = "m".repeat(32);
+    const os = "o".repeat(32);
+    await program.methods.initialiseHostRegistration(maxId, hostName, machineType, os, new anchor.BN(100), new anchor.BN(1))
+      .accounts({
+        registrar: admin.publicKey,
+        userKey: user.publicKey,
+      })
+      .rpc();
+    const [maxHost] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("host_machine"), user.publicKey.toBuffer(), Buffer.from(maxId)],
+      program.programId
+    );
+    const hostMachineAccount = await program.account.hostMachineRegistration.fetch(maxHost);
+    assert.ok(hostMachineAccount.hostName === hostName, "Full-length host name should be stored");
+    assert.ok(hostMachineAccount.os === os, "Full-length OS should be stored");
+
+    try {
+      await program.methods.initialiseHostRegistration("1004", hostName, machineType, "o".repeat(33), new anchor.BN(100), new anchor.BN(1))
+        .accounts({
+          registrar: admin.publicKey,
+          userKey: user.publicKey,
+        })
+        .rpc();
+      assert.fail("Overlong OS should be rejected");
+    } catch (err) {
+      assert.ok(err.toString().includes("InvalidHostMachineRegistrationOS"), "Should fail with InvalidHostMachineRegistrationOS");
+    }
+  });
+
+  it("stakes host collateral", async () => {
+    await program.methods.stakeHost(id, new anchor.BN(0.1 * anchor.web3.LAMPORTS_PER_SOL))
+      .accounts({
+        host: user.publicKey,
+      })
+      .signers([user])
+      .rpc();
+    const [hostStake] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("host_stake"), hostMachine.toBuffer()],
+      program.programId
+    );
+    const stake = await program.account.hostStake.fetch(hostStake);
+    assert.ok(stake.amount.toNumber() === 0.1 * anchor.web3.LAMPORTS_PER_SOL, "Stake should be locked for the host");
+  });
+
+  it("hands day-to-day operation to an operator key", async () => {
+    const tx = await program.methods.setHostKeys(id, operator.publicKey, admin.publicKey)
+      .accounts({
+        host: user.publicKey,
+      })
+      .signers([user])
+      .rpc();
+    console.log("Your transaction signature", tx);
+    const hostMachineAccount = await program.account.hostMachineRegistration.fetch(hostMachine);
+    assert.ok(hostMachineAccount.operator.equals(operator.publicKey), "Operator key should be set");
+    assert.ok(hostMachineAccount.payoutWallet.equals(admin.publicKey), "Payout wallet should be set");
+  });
+
   it("activate host machine", async () => {
     const tx = await program.methods.activateHost(id)
     .accounts({
         host: user.publicKey,
         user: admin.publicKey,
+        machineTier,
     })
     .signers([admin])
     .rpc();
     console.log("Your transaction signature", tx);
     const hostMachineAccount = await program.account.hostMachineRegistration.fetch(hostMachine);
-    assert.ok(hostMachineAccount.isActive === true, "Host machine should be active after activation");
+    assert.ok(hostMachineAccount.status.online !== undefined, "Host machine should be online after activation");
     assert.ok(hostMachineAccount.hostKey.equals(user.publicKey), "Host key should match user public key");
     assert.ok(hostMachineAccount.id === id, "Host machine ID should match the provided ID");
   });
 
+  it("records host heartbeats while online", async () => {
+    const before = await program.account.hostMachineRegistration.fetch(hostMachine);
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+    const tx = await program.methods.hostHeartbeat(id)
+      .accounts({
+        pinger: operator.publicKey,
+        operatorRole: null,
+        host: user.publicKey,
+      })
+      .signers([operator])
+      .rpc();
+    console.log("Your transaction signature", tx);
+    const hostMachineAccount = await program.account.hostMachineRegistration.fetch(hostMachine);
+    assert.ok(hostMachineAccount.lastHeartbeat.gt(before.lastHeartbeat), "Heartbeat time should advance");
+    assert.ok(hostMachineAccount.coveredSeconds.gt(before.coveredSeconds), "Heartbeat should credit covered uptime");
+  });
+
+  it("checkpoints fractional-hour rewards without deactivating", async () => {
+    const tx = await program.methods.checkpointRewards(id)
+      .accounts({
+        user: user.publicKey,
+        host: user.publicKey,
+      })
+      .signers([user])
+      .rpc();
+    console.log("Your transaction signature", tx);
+    const hostMachineAccount = await program.account.hostMachineRegistration.fetch(hostMachine);
+    assert.ok(hostMachineAccount.status.online !== undefined, "Host should stay online after a checkpoint");
+    assert.ok(hostMachineAccount.coveredSeconds.toNumber() === 0, "Covered uptime should be accrued");
+    assert.ok(hostMachineAccount.rewardCarry.toNumber() > 0, "Sub-lamport rewards should carry over");
+  });
+
+  it("claims rewards while the host stays online", async () => {
+    const tx = await program.methods.claimRewards(id)
+      .accounts({
+        host: user.publicKey,
+        payoutWallet: admin.publicKey,
+      })
+      .signers([user])
+      .rpc();
+    console.log("Your transaction signature", tx);
+    const hostMachineAccount = await program.account.hostMachineRegistration.fetch(hostMachine);
+    assert.ok(hostMachineAccount.status.online !== undefined, "Host should stay online after claiming");
+    assert.ok(hostMachineAccount.startedAt.toNumber() > 0, "Claiming should not reset the activation time");
+    assert.ok(hostMachineAccount.earned.toNumber() === 0, "Accrued rewards should be paid out");
+  });
+
+  it("rents the host machine and credits the host on finalize", async () => {
+    const rentalId = "2001";
+    const [feeVault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), Buffer.from([2])],
+      program.programId
+    );
+    if (!(await program.account.vaultAccount.fetchNullable(feeVault))) {
+      await program.methods.initializeVault({ feePool: {} })
+        .accounts({ admin: admin.publicKey })
+        .rpc();
+    }
+
+    await program.methods.startRentalWithEscrow(new anchor.BN(0.5 * anchor.web3.LAMPORTS_PER_SOL), rentalId, null, "e2-medium")
+      .accounts({
+        payer: user.publicKey,
+        admin: admin.publicKey,
+        machineTier,
+        hostMachine,
+      })
+      .signers([user])
+      .rpc();
+    let hostMachineAccount = await program.account.hostMachineRegistration.fetch(hostMachine);
+    assert.ok(hostMachineAccount.occupied, "Host machine should be occupied while rented");
+
+    // Let some billable time pass before settling
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+
+    await program.methods.finaliseRentalWithEscrow(rentalId)
+      .accounts({
+        caller: user.publicKey,
+        user: user.publicKey,
+        admin: admin.publicKey,
+        referrer: null,
+        hostMachine,
+      })
+      .signers([user])
+      .rpc();
+    hostMachineAccount = await program.account.hostMachineRegistration.fetch(hostMachine);
+    assert.ok(!hostMachineAccount.occupied, "Host machine should be released after finalize");
+    assert.ok(hostMachineAccount.earned.toNumber() > 0, "Host should be credited with the rental payment");
+  });
+
+  it("lets anyone release a host once the renter's escrow runs out", async () => {
+    const rentalId = "2002";
+    // The SOL tier bills 1000 lamports per second, so this escrow is used up after a second
+    await program.methods.startRentalWithEscrow(new anchor.BN(1000), rentalId, null, "e2-medium")
+      .accounts({
+        payer: user.publicKey,
+        admin: admin.publicKey,
+        machineTier,
+        hostMachine,
+      })
+      .signers([user])
+      .rpc();
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+
+    await program.methods.finaliseRentalWithEscrow(rentalId)
+      .accounts({
+        caller: admin.publicKey,
+        user: user.publicKey,
+        admin: admin.publicKey,
+        referrer: null,
+        hostMachine,
+      })
+      .rpc();
+    const hostMachineAccount = await program.account.hostMachineRegistration.fetch(hostMachine);
+    assert.ok(!hostMachineAccount.occupied, "Host machine should be released once the escrow is exhausted");
+  });
+
   it("deactivate host machine", async () => {
     const tx = await program.methods.deactivateHost(id)
       .accounts({
@@ -145,14 +381,14 @@ describe("contract", () => {
       .rpc();
     console.log("Your transaction signature", tx);
     const hostMachineAccount = await program.account.hostMachineRegistration.fetch(hostMachine);
-    assert.ok(hostMachineAccount.isActive === false, "Host machine should be inactive after deactivation");
+    assert.ok(hostMachineAccount.status.offline !== undefined, "Host machine should be offline after deactivation");
   });
 
   it("claims rewarded SOL", async () => {
-    const tx = await program.methods.claimRewards(id, secretKey)
+    const tx = await program.methods.claimRewards(id)
       .accounts({
         host: user.publicKey,
-        admin: admin.publicKey,
+        payoutWallet: admin.publicKey,
       })
       .signers([user])
       .rpc();
@@ -161,17 +397,186 @@ describe("contract", () => {
     assert.ok(hostMachineAccount.earned.toNumber() === 0, "Host machine should have earned rewards");
   });
 
-  it("punish host machine", async () => {
-    const tx = await program.methods.penalizeHost(id)
+  it("slashes a share of the host's stake into the insurance fund", async () => {
+    const [insuranceVault] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), Buffer.from([3])],
+      program.programId
+    );
+    if (!(await program.account.vaultAccount.fetchNullable(insuranceVault))) {
+      await program.methods.initializeVault({ insurance: {} })
+        .accounts({ admin: admin.publicKey })
+        .rpc();
+    }
+    const [hostStake] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("host_stake"), hostMachine.toBuffer()],
+      program.programId
+    );
+    const stakeBefore = await program.account.hostStake.fetch(hostStake);
+    const insuranceBefore = await anchor.getProvider().connection.getBalance(insuranceVault);
+    const liabilitiesBefore = (await program.account.vaultAccount.fetch(insuranceVault)).liabilities;
+    const evidenceHash = Array.from(Buffer.alloc(32, 7));
+
+    const tx = await program.methods.slashHost(id, 2500, evidenceHash)
       .accounts({
-        admin: admin.publicKey,
+        slasher: admin.publicKey,
         user: user.publicKey,
+        hostStake,
       })
       .signers([admin])
       .rpc();
     console.log("Your transaction signature", tx);
     const hostMachineAccount = await program.account.hostMachineRegistration.fetch(hostMachine);
-    assert.ok(hostMachineAccount.penalized === true, "Host machine should be penalized");
-    assert.ok(hostMachineAccount.isActive === false, "Host machine should be inactive after penalization");
+    assert.ok(hostMachineAccount.status.penalized !== undefined, "Host machine should be penalized");
+    assert.ok(hostMachineAccount.slashCount.toNumber() === 1, "Slash should be recorded on the host");
+
+    const expected = stakeBefore.amount.muln(2500).divn(10_000);
+    const stakeAfter = await program.account.hostStake.fetch(hostStake);
+    assert.ok(stakeAfter.amount.eq(stakeBefore.amount.sub(expected)), "A quarter of the stake should be slashed");
+    const insuranceAfter = await anchor.getProvider().connection.getBalance(insuranceVault);
+    assert.ok(insuranceAfter - insuranceBefore === expected.toNumber(), "Slashed stake should move to the insurance fund");
+    const liabilitiesAfter = (await program.account.vaultAccount.fetch(insuranceVault)).liabilities;
+    assert.ok(liabilitiesAfter.eq(liabilitiesBefore.add(expected)), "Slashed funds should be held against an appeal");
+
+    const [slashRecord] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("slash_record"), hostMachine.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const record = await program.account.slashRecord.fetch(slashRecord);
+    assert.ok(record.slashBps === 2500, "Slash record should keep the percentage");
+    assert.deepStrictEqual(record.evidenceHash, evidenceHash, "Slash record should keep the evidence hash");
+  });
+
+  it("lets the host appeal and an arbiter reinstate it with the slashed funds", async () => {
+    await program.methods.setAppealWindow(new anchor.BN(3600))
+      .accounts({ admin: admin.publicKey })
+      .rpc();
+    const [slashRecord] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("slash_record"), hostMachine.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const [hostStake] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("host_stake"), hostMachine.toBuffer()],
+      program.programId
+    );
+    const counterEvidence = Array.from(Buffer.alloc(32, 9));
+
+    await program.methods.appealSlash(id, new anchor.BN(0), counterEvidence)
+      .accounts({ host: user.publicKey })
+      .signers([user])
+      .rpc();
+    let record = await program.account.slashRecord.fetch(slashRecord);
+    assert.deepStrictEqual(record.appealHash, counterEvidence, "Appeal should keep the counter-evidence hash");
+
+    const stakeBefore = await program.account.hostStake.fetch(hostStake);
+    await program.methods.reverseSlash(id, new anchor.BN(0))
+      .accounts({
+        arbiter: admin.publicKey,
+        user: user.publicKey,
+      })
+      .rpc();
+    await program.methods.reinstateHost(id)
+      .accounts({
+        arbiter: admin.publicKey,
+        user: user.publicKey,
+      })
+      .rpc();
+    const hostMachineAccount = await program.account.hostMachineRegistration.fetch(hostMachine);
+    assert.ok(hostMachineAccount.status.offline !== undefined, "Reinstated host should be offline");
+    const stakeAfter = await program.account.hostStake.fetch(hostStake);
+    assert.ok(stakeAfter.amount.eq(stakeBefore.amount.add(record.stakeSlashed)), "Slashed stake should be restored");
+    record = await program.account.slashRecord.fetch(slashRecord);
+    assert.ok(record.reversed, "Slash should be marked as reversed");
+    assert.ok(record.settled, "Reversed slash should no longer be held");
+
+    try {
+      await program.methods.settleSlash(id, new anchor.BN(0))
+        .accounts({
+          caller: admin.publicKey,
+          user: user.publicKey,
+        })
+        .rpc();
+      assert.fail("A reversed slash can't be settled again");
+    } catch (err) {
+      assert.ok(err.toString().includes("SlashAlreadySettled"), "Should fail with SlashAlreadySettled");
+    }
+  });
+
+  it("approves a self-submitted host registration", async () => {
+    const pendingId = "1002";
+    const [pendingHost] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("host_machine"), user.publicKey.toBuffer(), Buffer.from(pendingId)],
+      program.programId
+    );
+    await program.methods.registerHost(pendingId, "host_name", "e2-medium", "linux", new anchor.BN(100), new anchor.BN(1))
+      .accounts({ host: user.publicKey })
+      .signers([user])
+      .rpc();
+    let hostMachineAccount = await program.account.hostMachineRegistration.fetch(pendingHost);
+    assert.ok(hostMachineAccount.status.pending !== undefined, "Self-registration should wait for approval");
+    const [pendingStake] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("host_stake"), pendingHost.toBuffer()],
+      program.programId
+    );
+    const stake = await program.account.hostStake.fetch(pendingStake);
+    assert.ok(stake.amount.eq(new anchor.BN(0.1 * anchor.web3.LAMPORTS_PER_SOL)), "Registration should post the tier's minimum stake");
+
+    await program.methods.approveHost(pendingId)
+      .accounts({
+        registrar: admin.publicKey,
+        host: user.publicKey,
+      })
+      .rpc();
+    hostMachineAccount = await program.account.hostMachineRegistration.fetch(pendingHost);
+    assert.ok(hostMachineAccount.status.registered !== undefined, "Approved host should be registered");
+  });
+
+  it("rejects a self-submitted host registration and refunds its stake", async () => {
+    const rejectedId = "1003";
+    const [rejectedHost] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("host_machine"), user.publicKey.toBuffer(), Buffer.from(rejectedId)],
+      program.programId
+    );
+    const [hostStake] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("host_stake"), rejectedHost.toBuffer()],
+      program.programId
+    );
+    await program.methods.registerHost(rejectedId, "host_name", "e2-medium", "linux", new anchor.BN(100), new anchor.BN(1))
+      .accounts({ host: user.publicKey })
+      .signers([user])
+      .rpc();
+    const balanceBefore = await anchor.getProvider().connection.getBalance(user.publicKey);
+
+    await program.methods.rejectHost(rejectedId)
+      .accounts({
+        registrar: admin.publicKey,
+        host: user.publicKey,
+        hostStake,
+      })
+      .rpc();
+    assert.ok(await program.account.hostMachineRegistration.fetchNullable(rejectedHost) === null, "Rejected registration should be closed");
+    assert.ok(await program.account.hostStake.fetchNullable(hostStake) === null, "Rejected host stake should be closed");
+    const balanceAfter = await anchor.getProvider().connection.getBalance(user.publicKey);
+    assert.ok(balanceAfter - balanceBefore >= 0.1 * anchor.web3.LAMPORTS_PER_SOL, "Stake and rent should be refunded");
+  });
+
+  it("updates host specs and pricing with admin co-signature", async () => {
+    const updatedId = "1002";
+    const [updatedHost] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("host_machine"), user.publicKey.toBuffer(), Buffer.from(updatedId)],
+      program.programId
+    );
+    const hostName = "a-considerably-longer-host-name-than-before";
+    const tx = await program.methods.updateHost(updatedId, hostName, "e2-medium", "ubuntu-22.04", new anchor.BN(200), new anchor.BN(2))
+      .accounts({
+        host: user.publicKey,
+        admin: admin.publicKey,
+      })
+      .signers([user])
+      .rpc();
+    console.log("Your transaction signature", tx);
+    const hostMachineAccount = await program.account.hostMachineRegistration.fetch(updatedHost);
+    assert.ok(hostMachineAccount.hostName === hostName, "Host name should grow into the reallocated account");
+    assert.ok(hostMachineAccount.os === "ubuntu-22.04", "OS should be updated");
+    assert.ok(hostMachineAccount.solPerHour.toNumber() === 2, "Reward rate should be updated");
   });
 });
@@ -5,8 +5,7 @@ import { ArrowLeft, Trash2 } from "lucide-react";
 import axios from "axios";
 import { BACKEND_URL } from "@/config";
 import type { VM } from "types/vm";
-import { calculatePrice } from "@/lib/vm";
-import { EndRentalSession, transferFromVault } from "@/lib/contract";
+import { EarlyTerminateRental } from "@/lib/contract";
 import { toast } from "react-toastify";
 import { useState } from "react";
 import { useAnchorWallet } from "@solana/wallet-adapter-react";
@@ -21,12 +20,11 @@ export const Header = ({ vm }: { vm: VM }) => {
 
     const handleDelete = async () => {
       if (vm.PaymentType === "DURATION") {
-        await EndRentalSession(vm.id, wallet!)
+        // Refunds the unused rental time on-chain
+        await EarlyTerminateRental(vm.id, wallet!)
       }
       else {
-        const timeElapsed = (new Date().getTime() - new Date(vm.createdAt).getTime()) / (1000 * 60);
-        const amount = await calculatePrice(vm?.VMConfig?.machineType!, Number(vm?.VMConfig?.diskSize), timeElapsed);
-        await FinalizeRentalWithEscrow(wallet!, vm.id, Number(amount));
+        await FinalizeRentalWithEscrow(wallet!, vm.id);
       }
         setLoading(true);
         try {
@@ -36,10 +34,6 @@ export const Header = ({ vm }: { vm: VM }) => {
               },
             });
             if (res.status === 200) {
-              if(res.data.remainingTime > 0) {
-                const remainingPrice = await calculatePrice(vm?.VMConfig?.machineType!, Number(vm?.VMConfig?.diskSize), res.data.remainingTime);
-                await transferFromVault(Number(remainingPrice), vm?.id as string, wallet!);
-              }
               setLoading(false);
               toast.success("VM deleted successfully!", {
                 position: "bottom-right",
@@ -1,17 +1,33 @@
 import { type AnchorWallet } from "@solana/wallet-adapter-react";
 import { BN } from "bn.js";
 import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
-import { ADMIN_KEY, SECRET_KEY } from "@/config";
-import { Contarct } from "./contract";
+import { ADMIN_KEY } from "@/config";
+import { type Program } from "@coral-xyz/anchor";
+import { Contarct, VaultPda } from "./contract";
 
-export const StartRentalSessionWithEscrow = async (wallet: AnchorWallet, amount: number, id: String) => {
+// The escrow vault is derived from the admin at rental start, which may have rotated since
+const FetchEscrowSession = async (program: Program, user: PublicKey, id: String) => {
+    const [escrowSessionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("escrow_session"), user.toBuffer(), Buffer.from(id)],
+        program.programId
+    );
+    return (program.account as any).escrowSession.fetch(escrowSessionPda);
+}
+
+export const StartRentalSessionWithEscrow = async (wallet: AnchorWallet, amount: number, id: String, machineType: string) => {
     const program = Contarct(wallet);
 
     try {
-        const tx = await program.methods.startRentalWithEscrow((new BN(amount * LAMPORTS_PER_SOL)), id)
+        const [machineTier] = PublicKey.findProgramAddressSync(
+            [Buffer.from("machine_tier"), Buffer.from(machineType), PublicKey.default.toBuffer()],
+            program.programId
+        );
+        const tx = await program.methods.startRentalWithEscrow(new BN(amount * LAMPORTS_PER_SOL), id, null, machineType)
         .accounts({
             payer: wallet.publicKey,
             admin: new PublicKey(ADMIN_KEY),
+            machineTier,
+            hostMachine: null,
         })
         .rpc();
         return {
@@ -29,10 +45,11 @@ export const TopUpEscrowSession = async (wallet: AnchorWallet, id: String, amoun
     const program = Contarct(wallet);
 
     try {
+        const escrowSession = await FetchEscrowSession(program, wallet.publicKey, id);
         const tx = await program.methods.topUpEscrow(id, new BN(amount * LAMPORTS_PER_SOL))
         .accounts({
             user: wallet.publicKey,
-            admin: new PublicKey(ADMIN_KEY),
+            admin: escrowSession.vaultAdmin,
         })
         .rpc();
         return {
@@ -46,14 +63,27 @@ export const TopUpEscrowSession = async (wallet: AnchorWallet, id: String, amoun
     }
 }
 
-export const FinalizeRentalWithEscrow = async (wallet: AnchorWallet, id: String, amount: number) => {
+export const FinalizeRentalWithEscrow = async (wallet: AnchorWallet, id: String) => {
     const program = Contarct(wallet);
 
     try {
-        const tx = await program.methods.finaliseRentalWithEscrow(id, new BN(amount * LAMPORTS_PER_SOL), SECRET_KEY)
+        const [rentalSessionPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("rental_session"), wallet.publicKey.toBuffer(), Buffer.from(id)],
+            program.programId
+        );
+        const rentalSession = await (program.account as any).rentalSession.fetch(rentalSessionPda);
+        const escrowSession = await FetchEscrowSession(program, wallet.publicKey, id);
+
+        const tx = await program.methods.finaliseRentalWithEscrow(id)
         .accounts({
+            caller: wallet.publicKey,
             user: wallet.publicKey,
-            admin: new PublicKey(ADMIN_KEY),
+            admin: escrowSession.vaultAdmin,
+            vaultAccount: VaultPda(program.programId, "rentalTreasury"),
+            feeVault: VaultPda(program.programId, "feePool"),
+            rewardsVault: VaultPda(program.programId, "rewardsPool"),
+            hostMachine: rentalSession.hostMachine,
+            referrer: escrowSession.referrer,
         })
         .rpc();
         return {
@@ -65,4 +95,4 @@ export const FinalizeRentalWithEscrow = async (wallet: AnchorWallet, id: String,
         console.error("Error finalizing rental with escrow", e);
         return null;
     }
-}
\ No newline at end of file
+}
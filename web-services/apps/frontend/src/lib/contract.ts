@@ -4,10 +4,14 @@ import idl from '../../idl/contract.json';
 import { type AnchorWallet } from "@solana/wallet-adapter-react";
 import { BN } from "bn.js";
 import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
-import { ADMIN_KEY, SECRET_KEY } from "@/config";
 
 // const network = clusterApiUrl('devnet');
 
+// Order matches the on-chain VaultKind and Role enums, which are used as single-byte PDA seeds.
+export const VAULT_KINDS = ["rentalTreasury", "rewardsPool", "feePool", "insurance"] as const;
+export type VaultKind = typeof VAULT_KINDS[number];
+const TREASURER_ROLE = 0;
+
 export function Contarct(wallet: AnchorWallet): Program {
     if (!wallet) {
         throw new Error("Wallet not connected");
@@ -16,22 +20,30 @@ export function Contarct(wallet: AnchorWallet): Program {
     const provider = new AnchorProvider(connection, wallet, {});
 
     const program = new Program(idl as any, provider);
-    
+
     return program;
 }
- 
 
-export const InitiatesVaultAccount = async (wallet: AnchorWallet, secretKey: string) => {
+export function VaultPda(programId: PublicKey, kind: VaultKind): PublicKey {
+    const [vaultAccount] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vault"), Buffer.from([VAULT_KINDS.indexOf(kind)])],
+        programId
+    );
+    return vaultAccount;
+}
+
+export const InitiatesVaultAccount = async (wallet: AnchorWallet, kind: VaultKind) => {
     const program = Contarct(wallet);
-  
+
     if (!wallet) {
       console.error("Wallet not connected");
       return null;
     }
-  
+
     try {
-        const tx = await program.methods.initializeVault(secretKey).accounts({
+        const tx = await program.methods.initializeVault({ [kind]: {} }).accounts({
             admin: wallet.publicKey,
+            vaultAccount: VaultPda(program.programId, kind),
         })
         .rpc();
         const transaction = await program.provider.connection.confirmTransaction(tx);
@@ -51,20 +63,18 @@ export const InitiatesVaultAccount = async (wallet: AnchorWallet, secretKey: str
     }
 };
 
-export const FundVaultAccount = async (wallet: AnchorWallet, amount: number, secretKey: String) => {
+export const FundVaultAccount = async (wallet: AnchorWallet, amount: number, kind: VaultKind) => {
     const program = Contarct(wallet);
-  
+
     if (!wallet) {
       console.error("Wallet not connected");
       return null;
     }
-    const [vaultAccount] = await PublicKey.findProgramAddressSync(
-        [Buffer.from("vault_account"), wallet.publicKey.toBuffer(), Buffer.from(secretKey)],
-        program.programId
-    );
+    const vaultAccount = VaultPda(program.programId, kind);
     try {
-        const tx = await program.methods.fundVault(new BN(amount * LAMPORTS_PER_SOL), secretKey).accounts({
-            admin: wallet.publicKey,
+        const tx = await program.methods.fundVault(new BN(amount * LAMPORTS_PER_SOL), { [kind]: {} }).accounts({
+            funder: wallet.publicKey,
+            vaultAccount,
         })
         .rpc();
         const vaultAccountBalance = await program.provider.connection.getBalance(vaultAccount);
@@ -85,58 +95,18 @@ export const FundVaultAccount = async (wallet: AnchorWallet, amount: number, sec
     }
 }
 
-export const transferFromVault = async (amount: number, id: String, wallet: AnchorWallet) => {
+export const EarlyTerminateRental = async (id: String, wallet: AnchorWallet) => {
     const program = Contarct(wallet);
-  
-    if (!wallet) {
-      console.error("Wallet not connected");
-      return null;
-    }
-  
-    try {
-        const tx = await program.methods.transferFromVault(new BN(amount * LAMPORTS_PER_SOL), id, SECRET_KEY)
-        .accounts({
-            admin: new PublicKey(ADMIN_KEY),
-            payer: wallet.publicKey,
-        })
-        .rpc();
-
-        const transaction = await program.provider.connection.confirmTransaction(tx);
-        if (transaction.value.err) {
-            console.error("Transaction failed", transaction.value.err);
-            return null;
-        }
-        return {
-            success: true,
-            signature: tx,
-            message: "Funds transferred from vault account successfully",
-        };
-    } catch (error) {
-      console.error("Error transferring funds from vault account", error);
-      return null;
-    }
-}
 
-export const EndRentalSession = async (id: String, wallet: AnchorWallet) => {
-    const program = Contarct(wallet);
-    const [rentalSessionPDA] = PublicKey.findProgramAddressSync(
-        [
-            Buffer.from("rental_session"),
-            wallet.publicKey.toBuffer(),
-            Buffer.from(id)
-        ],
-        program.programId
-    );
-  
     if (!wallet) {
       console.error("Wallet not connected");
       return null;
     }
-  
+
     try {
-        const tx = await program.methods.endRentalSession(id, wallet.publicKey).accounts({
+        const tx = await program.methods.earlyTerminateRental(id).accounts({
             payer: wallet.publicKey,
-            rentalSession: rentalSessionPDA,
+            vaultAccount: VaultPda(program.programId, "rentalTreasury"),
         })
         .rpc();
         const transaction = await program.provider.connection.confirmTransaction(tx);
@@ -147,37 +117,36 @@ export const EndRentalSession = async (id: String, wallet: AnchorWallet) => {
         return {
             success: true,
             signature: tx,
-            message: "Rental session ended successfully",
+            message: "Rental session terminated and unused time refunded",
         };
     } catch (error) {
-      console.error("Error ending rental session", error);
+      console.error("Error terminating rental session", error);
       return null;
     }
 }
 
 export const TransferToVaultAndStartRental = async (amount: number, duration: number, id: String, wallet: AnchorWallet) => {
     const program = Contarct(wallet);
-  
+
     if (!wallet) {
       console.error("Wallet not connected");
       return null;
     }
-  
+
     try {
         const [rentalSessionPda] = PublicKey.findProgramAddressSync(
             [Buffer.from("rental_session"), wallet.publicKey.toBuffer(), Buffer.from(id)],
             program.programId
         );
-  
+
         const tx = await program.methods.transferToVaultAndRent(
             new BN(amount * LAMPORTS_PER_SOL),
             new BN(duration * 60),
             id,
-            SECRET_KEY
         )
         .accounts({
-            admin: new PublicKey(ADMIN_KEY),
             payer: wallet.publicKey,
+            vaultAccount: VaultPda(program.programId, "rentalTreasury"),
         })
         .rpc();
         const transaction = await program.provider.connection.confirmTransaction(tx);
@@ -185,7 +154,7 @@ export const TransferToVaultAndStartRental = async (amount: number, duration: nu
             console.error("Transaction failed", transaction.value.err);
             return null;
         }
-        
+
         return {
             success: true,
             signature: tx,
@@ -198,17 +167,23 @@ export const TransferToVaultAndStartRental = async (amount: number, duration: nu
     }
 }
 
-export const WithdrawFromVault = async (amount: number, wallet: AnchorWallet, secretKey: string) => {
+export const WithdrawFromVault = async (amount: number, wallet: AnchorWallet, kind: VaultKind) => {
     const program = Contarct(wallet);
-  
+
     if (!wallet) {
       console.error("Wallet not connected");
       return null;
     }
-  
+
     try {
-        const tx = await program.methods.withdrawFunds(new BN(amount * LAMPORTS_PER_SOL), secretKey).accounts({
-            admin: wallet.publicKey,
+        const [operatorRole] = PublicKey.findProgramAddressSync(
+            [Buffer.from("operator_role"), wallet.publicKey.toBuffer(), Buffer.from([TREASURER_ROLE])],
+            program.programId
+        );
+        const tx = await program.methods.withdrawFunds(new BN(amount * LAMPORTS_PER_SOL), { [kind]: {} }).accounts({
+            treasurer: wallet.publicKey,
+            operatorRole,
+            vaultAccount: VaultPda(program.programId, kind),
         })
         .rpc();
         const transaction = await program.provider.connection.confirmTransaction(tx);
@@ -227,20 +202,16 @@ export const WithdrawFromVault = async (amount: number, wallet: AnchorWallet, se
     }
 }
 
-export const GetVaultBalance = async (wallet: AnchorWallet, secretKey: string) => {
+export const GetVaultBalance = async (wallet: AnchorWallet, kind: VaultKind) => {
     const program = Contarct(wallet);
-  
+
     if (!wallet) {
       console.error("Wallet not connected");
       return null;
     }
-  
+
     try {
-        const vaultAccount = await PublicKey.findProgramAddressSync(
-            [Buffer.from("vault_account"), wallet.publicKey.toBuffer(), Buffer.from(secretKey)],
-            program.programId
-        );
-        const vaultAccountBalance = await program.provider.connection.getBalance(vaultAccount[0]);
+        const vaultAccountBalance = await program.provider.connection.getBalance(VaultPda(program.programId, kind));
         return {
             success: true,
             balance: vaultAccountBalance / LAMPORTS_PER_SOL,
@@ -250,4 +221,4 @@ export const GetVaultBalance = async (wallet: AnchorWallet, secretKey: string) =
       console.error("Error fetching vault balance", error);
       return null;
     }
-}
\ No newline at end of file
+}
@@ -1,15 +1,25 @@
 import { type AnchorWallet } from "@solana/wallet-adapter-react";
-import { PublicKey } from "@solana/web3.js";
-import { ADMIN_KEY, SECRET_KEY } from "@/config";
-import { Contarct } from "./contract";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { type Program } from "@coral-xyz/anchor";
+import { Contarct, VaultPda } from "./contract";
+
+const FetchHostMachine = async (program: Program, hostKey: PublicKey, machineId: String) => {
+    const [pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("host_machine"), hostKey.toBuffer(), Buffer.from(machineId)],
+        program.programId
+    );
+    return (program.account as any).hostMachineRegistration.fetch(pda);
+}
 
 export const claimSolana = async (wallet: AnchorWallet, id: String) => {
     try {
         const program = Contarct(wallet);
-        const txn = await program.methods.claimRewards(id, SECRET_KEY)
+        const hostMachine = await FetchHostMachine(program, wallet.publicKey, id);
+        const txn = await program.methods.claimRewards(id)
         .accounts({
-            admin: new PublicKey(ADMIN_KEY),
             host: wallet.publicKey,
+            payoutWallet: hostMachine.payoutWallet,
+            vaultAccount: VaultPda(program.programId, "rewardsPool"),
         })
         .rpc();
 
@@ -32,13 +42,6 @@ export const claimSolana = async (wallet: AnchorWallet, id: String) => {
 
 export async function getEarnedSOL(machineId: string, userPublicKey: PublicKey, wallet: AnchorWallet) {
     const program = Contarct(wallet);
-    const [pda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("host_machine"), userPublicKey.toBuffer(), Buffer.from(machineId)],
-        program.programId
-    );
-    const accountInfo = await program.provider.connection.getAccountInfo(pda);
-    if (!accountInfo) {
-        throw new Error("Account not found");
-    }
-    return Number(accountInfo.data.readBigUInt64LE(0) / BigInt(1e9));
-}
\ No newline at end of file
+    const hostMachine = await FetchHostMachine(program, userPublicKey, machineId);
+    return hostMachine.earned.toNumber() / LAMPORTS_PER_SOL;
+}
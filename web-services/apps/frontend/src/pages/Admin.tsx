@@ -4,6 +4,7 @@ import { Card, CardContent, CardDescription, CardHeader, CardTitle } from '@/com
 import { Button } from '@/components/ui/button';
 import { Input } from '@/components/ui/input';
 import { Label } from '@/components/ui/label';
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from '@/components/ui/select';
 import { Tabs, TabsContent, TabsList, TabsTrigger } from '@/components/ui/tabs';
 import { Alert, AlertDescription } from '@/components/ui/alert';
 import { Badge } from '@/components/ui/badge';
@@ -24,16 +25,15 @@ import {
 } from 'lucide-react';
 import { toast } from 'react-toastify';
 import type { VM } from 'types/vm';
-import { FundVaultAccount, GetVaultBalance, InitiatesVaultAccount, WithdrawFromVault } from '@/lib/contract';
+import { FundVaultAccount, GetVaultBalance, InitiatesVaultAccount, VAULT_KINDS, WithdrawFromVault, type VaultKind } from '@/lib/contract';
 import { useAnchorWallet } from '@solana/wallet-adapter-react';
 import axios from 'axios';
 import { ADMIN_KEY, BACKEND_URL } from '@/config';
 import { formatter } from '@/lib/FormatTime';
 
 interface VaultOperation {
-  secretKey: string;
+  kind: VaultKind;
   amount?: string;
-  address?: string;
 }
 
 export function AdminPage() {
@@ -45,10 +45,10 @@ export function AdminPage() {
     const itemsPerPage = 10;
 
     // Form states
-    const [initVault, setInitVault] = useState<VaultOperation>({ secretKey: '' });
-    const [fundVault, setFundVault] = useState<VaultOperation>({ secretKey: '', amount: '' });
-    const [withdrawVault, setWithdrawVault] = useState<VaultOperation>({ secretKey: '', amount: '', address: '' });
-    const [balanceCheck, setBalanceCheck] = useState<VaultOperation>({ secretKey: '' });
+    const [initVault, setInitVault] = useState<VaultOperation>({ kind: 'rentalTreasury' });
+    const [fundVault, setFundVault] = useState<VaultOperation>({ kind: 'rentalTreasury', amount: '' });
+    const [withdrawVault, setWithdrawVault] = useState<VaultOperation>({ kind: 'rentalTreasury', amount: '' });
+    const [balanceCheck, setBalanceCheck] = useState<VaultOperation>({ kind: 'rentalTreasury' });
     const [vms, setVMs] = useState<VM[]>([]);
     
     // Calculate pagination values
@@ -81,17 +81,12 @@ export function AdminPage() {
     }, [activeTab]);
 
     const handleVaultOperation = async (operation: string, data: VaultOperation) => {
-        if (!data.secretKey.trim()) {
-            toast.error('Secret key is required');
-            return;
-        }
-
         setIsLoading(true);
         
         try {        
             switch (operation) {
                 case 'init':
-                    const res = await InitiatesVaultAccount(wallet! ,data.secretKey)
+                    const res = await InitiatesVaultAccount(wallet!, data.kind)
                     if (!res?.success) {
                         toast.error('Failed to initialize vault account');
                         return;
@@ -104,7 +99,7 @@ export function AdminPage() {
                         toast.error('Valid amount is required');
                         return;
                     }
-                    const resp = await FundVaultAccount(wallet!, parseFloat(data.amount), data.secretKey);
+                    const resp = await FundVaultAccount(wallet!, parseFloat(data.amount), data.kind);
                     if (!resp?.success) {
                         toast.error('Failed to fund vault account');
                         return;
@@ -112,8 +107,8 @@ export function AdminPage() {
                     toast.success(`Vault funded with ${data.amount} SOL`);
                     break;
                 case 'withdraw':
-                    if (!data.amount || !data.address) {
-                        toast.error('Amount and address are required');
+                    if (!data.amount) {
+                        toast.error('Valid amount is required');
                         return;
                     }
                     const withdrawAmount = parseFloat(data.amount);
@@ -121,16 +116,16 @@ export function AdminPage() {
                         toast.error('Insufficient vault balance');
                         return;
                     }
-                    const respo = await WithdrawFromVault(withdrawAmount, wallet!, data.secretKey);
+                    const respo = await WithdrawFromVault(withdrawAmount, wallet!, data.kind);
                     if (!respo?.success) {
                         toast.error('Failed to withdraw from vault');
                         return;
                     }
 
-                    toast.success(`${withdrawAmount} SOL withdrawn to ${data.address.slice(0, 8)}...`);
+                    toast.success(`${withdrawAmount} SOL withdrawn to the treasurer wallet`);
                     break;
                 case 'balance':
-                    const balance = await GetVaultBalance(wallet!, data.secretKey);
+                    const balance = await GetVaultBalance(wallet!, data.kind);
                     if (balance === null) {
                         toast.error('Failed to fetch vault balance');
                         return;
@@ -262,19 +257,27 @@ export function AdminPage() {
                       Initialize Vault
                     </CardTitle>
                     <CardDescription>
-                      Create a new vault account with your secret key
+                      Create one of the program's vault accounts
                     </CardDescription>
                   </CardHeader>
                   <CardContent className="space-y-4">
                     <div className="space-y-2">
-                      <Label htmlFor="init-secret">Secret Key</Label>
-                      <Input
-                        id="init-secret"
-                        type="password"
-                        placeholder="Enter your secret key"
-                        value={initVault.secretKey}
-                        onChange={(e) => setInitVault({ ...initVault, secretKey: e.target.value })}
-                      />
+                      <Label htmlFor="init-kind">Vault</Label>
+                      <Select
+                        value={initVault.kind}
+                        onValueChange={(value) => setInitVault({ ...initVault, kind: value as VaultKind })}
+                      >
+                        <SelectTrigger id="init-kind">
+                          <SelectValue placeholder="Select vault" />
+                        </SelectTrigger>
+                        <SelectContent>
+                          {VAULT_KINDS.map((kind) =>
+                            <SelectItem key={kind} value={kind}>
+                              {kind}
+                            </SelectItem>
+                          )}
+                        </SelectContent>
+                      </Select>
                     </div>
                     <Button
                       onClick={() => handleVaultOperation('init', initVault)}
@@ -299,14 +302,22 @@ export function AdminPage() {
                   </CardHeader>
                   <CardContent className="space-y-4">
                     <div className="space-y-2">
-                      <Label htmlFor="fund-secret">Secret Key</Label>
-                      <Input
-                        id="fund-secret"
-                        type="password"
-                        placeholder="Enter your secret key"
-                        value={fundVault.secretKey}
-                        onChange={(e) => setFundVault({ ...fundVault, secretKey: e.target.value })}
-                      />
+                      <Label htmlFor="fund-kind">Vault</Label>
+                      <Select
+                        value={fundVault.kind}
+                        onValueChange={(value) => setFundVault({ ...fundVault, kind: value as VaultKind })}
+                      >
+                        <SelectTrigger id="fund-kind">
+                          <SelectValue placeholder="Select vault" />
+                        </SelectTrigger>
+                        <SelectContent>
+                          {VAULT_KINDS.map((kind) =>
+                            <SelectItem key={kind} value={kind}>
+                              {kind}
+                            </SelectItem>
+                          )}
+                        </SelectContent>
+                      </Select>
                     </div>
                     <div className="space-y-2">
                       <Label htmlFor="fund-amount">Amount (SOL)</Label>
@@ -337,19 +348,27 @@ export function AdminPage() {
                       Withdraw Funds
                     </CardTitle>
                     <CardDescription>
-                      Withdraw funds from your vault to an address
+                      Withdraw free vault balance to your treasurer wallet
                     </CardDescription>
                   </CardHeader>
                   <CardContent className="space-y-4">
                     <div className="space-y-2">
-                      <Label htmlFor="withdraw-secret">Secret Key</Label>
-                      <Input
-                        id="withdraw-secret"
-                        type="password"
-                        placeholder="Enter your secret key"
-                        value={withdrawVault.secretKey}
-                        onChange={(e) => setWithdrawVault({ ...withdrawVault, secretKey: e.target.value })}
-                      />
+                      <Label htmlFor="withdraw-kind">Vault</Label>
+                      <Select
+                        value={withdrawVault.kind}
+                        onValueChange={(value) => setWithdrawVault({ ...withdrawVault, kind: value as VaultKind })}
+                      >
+                        <SelectTrigger id="withdraw-kind">
+                          <SelectValue placeholder="Select vault" />
+                        </SelectTrigger>
+                        <SelectContent>
+                          {VAULT_KINDS.map((kind) =>
+                            <SelectItem key={kind} value={kind}>
+                              {kind}
+                            </SelectItem>
+                          )}
+                        </SelectContent>
+                      </Select>
                     </div>
                     <div className="space-y-2">
                       <Label htmlFor="withdraw-amount">Amount (SOL)</Label>
@@ -362,15 +381,6 @@ export function AdminPage() {
                         onChange={(e) => setWithdrawVault({ ...withdrawVault, amount: e.target.value })}
                       />
                     </div>
-                    <div className="space-y-2">
-                      <Label htmlFor="withdraw-address">Destination Address</Label>
-                      <Input
-                        id="withdraw-address"
-                        placeholder="Enter Solana address"
-                        value={withdrawVault.address}
-                        onChange={(e) => setWithdrawVault({ ...withdrawVault, address: e.target.value })}
-                      />
-                    </div>
                     <Button
                       onClick={() => handleVaultOperation('withdraw', withdrawVault)}
                       disabled={isLoading}
@@ -395,14 +405,22 @@ export function AdminPage() {
                   </CardHeader>
                   <CardContent className="space-y-4">
                     <div className="space-y-2">
-                      <Label htmlFor="balance-secret">Secret Key</Label>
-                      <Input
-                        id="balance-secret"
-                        type="password"
-                        placeholder="Enter your secret key"
-                        value={balanceCheck.secretKey}
-                        onChange={(e) => setBalanceCheck({ ...balanceCheck, secretKey: e.target.value })}
-                      />
+                      <Label htmlFor="balance-kind">Vault</Label>
+                      <Select
+                        value={balanceCheck.kind}
+                        onValueChange={(value) => setBalanceCheck({ ...balanceCheck, kind: value as VaultKind })}
+                      >
+                        <SelectTrigger id="balance-kind">
+                          <SelectValue placeholder="Select vault" />
+                        </SelectTrigger>
+                        <SelectContent>
+                          {VAULT_KINDS.map((kind) =>
+                            <SelectItem key={kind} value={kind}>
+                              {kind}
+                            </SelectItem>
+                          )}
+                        </SelectContent>
+                      </Select>
                     </div>
                     <Button
                       onClick={() => handleVaultOperation('balance', balanceCheck)}
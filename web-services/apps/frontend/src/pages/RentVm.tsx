@@ -102,7 +102,7 @@ export const RentVM = () => {
     setIsConfirmOpen(false);
     setPaymentStatus("Pending");
     const id = generateUUID().substring(0, 32);
-    const tx = paymentType === "duration" ?  await TransferToVaultAndStartRental(costPerMin * duration, duration, id ,wallet!) : await StartRentalSessionWithEscrow(wallet!, escrowAmount, id);
+    const tx = paymentType === "duration" ?  await TransferToVaultAndStartRental(costPerMin * duration, duration, id ,wallet!) : await StartRentalSessionWithEscrow(wallet!, escrowAmount, id, selectedVMConfig?.machineType!);
 
     if (!tx?.success) {
       toast.error("Transaction failed. Please try again.", {
@@ -8,10 +8,9 @@ import BN from "bn.js";
 const connection = new Connection(clusterApiUrl("devnet"));
 
 const secretKey = process.env.PRIVATE_KEY;
-const vaultSecretKey = process.env.SECRET_KEY; 
 
 if (!secretKey) {
-  throw new Error("SECRET_KEY environment variable is not set.");
+  throw new Error("PRIVATE_KEY environment variable is not set.");
 }
 const payerKeypair = Keypair.fromSecretKey(bs58.decode(secretKey));
 
@@ -33,6 +32,25 @@ const wallet = {
 const provider = new AnchorProvider(connection, wallet, AnchorProvider.defaultOptions());
 const program = new Program(idl as any, provider);
 
+// Order matches the on-chain VaultKind and Role enums, which are used as single-byte PDA seeds.
+const VaultKind = { RentalTreasury: 0, RewardsPool: 1 };
+const Role = { HostRegistrar: 1, SessionOperator: 3 };
+
+function vaultPda(kind: number) {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("vault"), Buffer.from([kind])],
+        program.programId
+    )[0];
+}
+
+// The worker key must hold the matching operator role for each call it makes.
+function operatorRolePda(role: number) {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("operator_role"), payerKeypair.publicKey.toBuffer(), Buffer.from([role])],
+        program.programId
+    )[0];
+}
+
 export async function endRentalSession(
     id: string,
     userPubKey: string,
@@ -61,8 +79,11 @@ export async function endRentalSession(
             const tx = await program.methods
                 .endRentalSession(id, userPublicKey)  
                 .accounts({
-                    payer: wallet.publicKey,          
-                    rentalSession: rentalSessionPDA,  
+                    operator: wallet.publicKey,
+                    operatorRole: operatorRolePda(Role.SessionOperator),
+                    rentalSession: rentalSessionPDA,
+                    vaultAccount: vaultPda(VaultKind.RentalTreasury),
+                    tokenLedger: null,
                 })
                 .signers([payerKeypair]) 
                 .rpc();
@@ -74,15 +95,23 @@ export async function endRentalSession(
 
             return tx;
         } else {
-            if (!vaultSecretKey) {
-                throw new Error("SECRET_KEY environment variable is not set for escrow termination.");
-            }
-            
+            const [escrowSessionPDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("escrow_session"), userPublicKey.toBuffer(), Buffer.from(id)],
+                program.programId
+            );
+            // The escrow vault is derived from the admin at rental start, which may have rotated since
+            const rentalSession = await (program.account as any).rentalSession.fetch(rentalSessionPDA);
+            const escrowSession = await (program.account as any).escrowSession.fetch(escrowSessionPDA);
+
             const txn = await program.methods
-                .forceTerminateRental(id, vaultSecretKey)
+                .forceTerminateRental(id)
                 .accounts({
-                    admin: wallet.publicKey,   
-                    user: userPublicKey,       
+                    operator: wallet.publicKey,
+                    operatorRole: operatorRolePda(Role.SessionOperator),
+                    admin: escrowSession.vaultAdmin,
+                    user: userPublicKey,
+                    hostMachine: rentalSession.hostMachine,
+                    vaultAccount: vaultPda(VaultKind.RentalTreasury),
                 })
                 .signers([payerKeypair])
                 .rpc();
@@ -112,7 +141,8 @@ export async function InitialiseHostPDA(id: string, hostName: string, machineTyp
         );
         const tx = await program.methods.initialiseHostRegistration(id, hostName, machineType, os, new BN(diskSize), new BN(pricePerHour))
         .accounts({
-            admin: payerKeypair.publicKey,
+            registrar: payerKeypair.publicKey,
+            operatorRole: operatorRolePda(Role.HostRegistrar),
             userKey: userPublicKey,
         })
         .signers([payerKeypair])
@@ -139,6 +169,7 @@ export async function deActivateHost(id: string, userPubKey: string) {
         .accounts({
             user: payerKeypair.publicKey,
             host: userPublicKey,
+            vaultAccount: vaultPda(VaultKind.RewardsPool),
         })
         .signers([payerKeypair])
         .rpc();
@@ -159,10 +190,21 @@ export async function activateHost(id: string, userPubKey: string) {
     const userPublicKey = new PublicKey(userPubKey);
 
     try {
+        const [hostMachinePda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("host_machine"), userPublicKey.toBuffer(), Buffer.from(id)],
+            program.programId
+        );
+        const hostMachine = await (program.account as any).hostMachineRegistration.fetch(hostMachinePda);
+        // Hosts are activated against the SOL-priced tier for their machine type
+        const [machineTier] = PublicKey.findProgramAddressSync(
+            [Buffer.from("machine_tier"), Buffer.from(hostMachine.machineType), PublicKey.default.toBuffer()],
+            program.programId
+        );
         const tx = await program.methods.activateHost(id)
         .accounts({
             user: payerKeypair.publicKey,
             host: userPublicKey,
+            machineTier,
         })
         .signers([payerKeypair])
         .rpc();